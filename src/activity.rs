@@ -0,0 +1,281 @@
+//! The activity timeline: the hand-written entries from `adventures.json`
+//! merged with essays, new repositories, releases and publications as their
+//! feeds report them.
+
+use crate::publications::{PublicationEntry, fetch_publications, normalize_orcid_id};
+use crate::requests::{RepositoryActivity, fetch_all_posts, get_repository_activity, log_warning};
+use crate::shared::{Post, get_env};
+use crate::timeline::{TimelineCategory, TimelineDate, TimelineEntry, TimelineSource};
+
+/// An entry from a feed, with what identifies the event it records.
+struct Generated {
+    entry: TimelineEntry,
+    /// The essay, repository, release or work, as a hand-written entry would
+    /// name it.
+    name: String,
+    /// Pages about it.
+    urls: Vec<String>,
+}
+
+/// `manual` merged with the feeds in `sources`, newest first, leaving out
+/// `manual` itself unless it is one of them. A feed that can't be read is
+/// left out rather than failing the timeline, and a feed entry that a shown
+/// hand-written one already describes is dropped in its favour.
+pub async fn merge_activity(
+    manual: Vec<TimelineEntry>,
+    sources: &[TimelineSource],
+) -> Vec<TimelineEntry> {
+    let wants = |source| sources.contains(&source);
+    let (essays, repositories, publications) = futures::join!(
+        async {
+            match wants(TimelineSource::Essays) {
+                true => essays().await,
+                false => Vec::new(),
+            }
+        },
+        async {
+            match wants(TimelineSource::Repositories) || wants(TimelineSource::Releases) {
+                true => {
+                    repositories(
+                        wants(TimelineSource::Repositories),
+                        wants(TimelineSource::Releases),
+                    )
+                    .await
+                }
+                false => Vec::new(),
+            }
+        },
+        async {
+            match wants(TimelineSource::Publications) {
+                true => publications().await,
+                false => Vec::new(),
+            }
+        },
+    );
+
+    let mut entries = match wants(TimelineSource::Manual) {
+        true => manual,
+        false => Vec::new(),
+    };
+    let generated: Vec<TimelineEntry> = essays
+        .into_iter()
+        .chain(repositories)
+        .chain(publications)
+        .filter(|generated| !entries.iter().any(|entry| describes(entry, generated)))
+        .map(|generated| generated.entry)
+        .collect();
+    entries.extend(generated);
+    // Stable, so hand-written entries lead their date.
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.date));
+    entries
+}
+
+async fn essays() -> Vec<Generated> {
+    let posts = match fetch_all_posts().await {
+        Ok(posts) => posts,
+        Err(error) => {
+            log_warning(&format!("activity: essays unavailable: {error}"));
+            return Vec::new();
+        }
+    };
+    posts.iter().filter_map(essay).collect()
+}
+
+fn essay(post: &Post) -> Option<Generated> {
+    let mut entry = feed_entry(
+        date(post.published_at.as_deref()?)?,
+        format!(
+            "Published the essay [{}](/essays/{})",
+            escape_markdown(&post.title),
+            post.slug
+        ),
+        TimelineCategory::Writing,
+        TimelineSource::Essays,
+    );
+    entry.body = (!post.brief.trim().is_empty()).then(|| escape_markdown(&post.brief));
+    Some(Generated {
+        entry,
+        name: post.title.clone(),
+        urls: vec![post.url.clone()],
+    })
+}
+
+async fn repositories(created: bool, releases: bool) -> Vec<Generated> {
+    let repositories = match get_repository_activity().await {
+        Ok(repositories) => repositories,
+        Err(error) => {
+            log_warning(&format!("activity: repositories unavailable: {error}"));
+            return Vec::new();
+        }
+    };
+    let mut generated = Vec::new();
+    for repository in &repositories {
+        if created {
+            generated.extend(repository_created(repository));
+        }
+        if releases {
+            generated.extend(repository_releases(repository));
+        }
+    }
+    generated
+}
+
+fn repository_created(repository: &RepositoryActivity) -> Option<Generated> {
+    let mut entry = feed_entry(
+        date(&repository.created_at)?,
+        format!(
+            "Created [{}]({})",
+            escape_markdown(&repository.name),
+            repository.url
+        ),
+        TimelineCategory::Project,
+        TimelineSource::Repositories,
+    );
+    entry.body = repository.description.as_deref().map(escape_markdown);
+    Some(Generated {
+        entry,
+        name: repository.name.clone(),
+        urls: vec![repository.url.clone()],
+    })
+}
+
+fn repository_releases(repository: &RepositoryActivity) -> impl Iterator<Item = Generated> + '_ {
+    repository.releases.iter().filter_map(|release| {
+        // Releases are often titled with just their version.
+        let name = match release
+            .name
+            .to_lowercase()
+            .contains(&repository.name.to_lowercase())
+        {
+            true => release.name.clone(),
+            false => format!("{} {}", repository.name, release.name),
+        };
+        Some(Generated {
+            entry: feed_entry(
+                date(&release.published_at)?,
+                format!("Released [{}]({})", escape_markdown(&name), release.url),
+                TimelineCategory::Release,
+                TimelineSource::Releases,
+            ),
+            name,
+            urls: vec![release.url.clone()],
+        })
+    })
+}
+
+async fn publications() -> Vec<Generated> {
+    let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+    let publications = match fetch_publications(&orcid_id).await {
+        Ok(publications) => publications,
+        Err(error) => {
+            log_warning(&format!("activity: publications unavailable: {error}"));
+            return Vec::new();
+        }
+    };
+    publications.iter().filter_map(publication).collect()
+}
+
+fn publication(publication: &PublicationEntry) -> Option<Generated> {
+    let mut entry = feed_entry(
+        date(publication.published_on.as_deref()?)?,
+        format!(
+            "Published [{}](/publications/{})",
+            escape_markdown(&publication.title),
+            publication.id
+        ),
+        TimelineCategory::Research,
+        TimelineSource::Publications,
+    );
+    entry.body = publication
+        .venue
+        .as_deref()
+        .map(|venue| format!("In *{}*", escape_markdown(venue)));
+    let doi = publication
+        .doi
+        .as_ref()
+        .map(|doi| format!("https://doi.org/{doi}"));
+    Some(Generated {
+        entry,
+        name: publication.title.clone(),
+        urls: doi.into_iter().chain(publication.url.clone()).collect(),
+    })
+}
+
+fn feed_entry(
+    date: TimelineDate,
+    title: String,
+    category: TimelineCategory,
+    source: TimelineSource,
+) -> TimelineEntry {
+    TimelineEntry {
+        date,
+        title,
+        body: None,
+        category,
+        icon: None,
+        location: None,
+        links: Vec::new(),
+        images: Vec::new(),
+        source,
+    }
+}
+
+/// The date of a `YYYY[-MM[-DD]]` string or an RFC 3339 timestamp.
+fn date(value: &str) -> Option<TimelineDate> {
+    let day = value.split('T').next()?.trim();
+    TimelineDate::try_from(day.to_string()).ok()
+}
+
+/// Whether a hand-written entry records the same event as a feed entry: it
+/// is dated within a month of it, and links to or names what it is about.
+fn describes(manual: &TimelineEntry, generated: &Generated) -> bool {
+    let (a, b) = (manual.date, generated.entry.date);
+    let close = match (a.month, b.month) {
+        (Some(a_month), Some(b_month)) => {
+            let months = |year: i32, month: u32| year * 12 + month as i32;
+            (months(a.year, a_month) - months(b.year, b_month)).abs() <= 1
+        }
+        _ => a.year == b.year,
+    };
+    if !close {
+        return false;
+    }
+
+    let text = std::iter::once(manual.title.as_str())
+        .chain(manual.body.as_deref())
+        .chain(manual.links.iter().map(|link| link.url.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    let name = generated.name.to_lowercase();
+    generated
+        .urls
+        .iter()
+        .any(|url| text.contains(&bare_url(url)))
+        || (name.chars().count() >= 4 && text.contains(&name))
+}
+
+/// `url` in lowercase without its scheme, `www.` or a trailing slash, so
+/// `https://www.example.com/a/` and `http://example.com/a` compare equal.
+fn bare_url(url: &str) -> String {
+    let url = url.to_lowercase();
+    let url = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    url.trim_start_matches("www.")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// `text` with the characters markdown acts on escaped, for titles that go
+/// into a line of markdown.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if "\\`*_[]()<>#!|~".contains(character) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
@@ -1,200 +1,271 @@
+use crate::activity::merge_activity;
 use crate::components::PageLayout;
-use axum::response::{Html, IntoResponse};
+use crate::components::filter::{FilterNav, FilterNavProps, Filters};
+use crate::datastar::{Event, ExecuteScript, MergeFragments, MergeMode, Sse};
+use crate::shared::escape_xml;
+use crate::shared::{PageQuery, TimelineQuery};
+use crate::timeline::{TimelineCategory, TimelineEntry, TimelineSource, parse_timeline};
+use axum::{
+    extract::Query,
+    response::{Html, IntoResponse},
+};
 use comrak::{Options, markdown_to_html};
-use momenta::nodes::DefaultProps;
 use momenta::prelude::*;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-#[derive(Deserialize, Serialize, Default, Clone)]
-pub struct AdventuresData {
-    #[serde(flatten)]
-    pub years: HashMap<String, HashMap<String, Vec<String>>>,
+/// Every hand-written timeline entry, newest first.
+fn load_timeline() -> Vec<TimelineEntry> {
+    parse_timeline(include_str!("./adventures.json")).unwrap_or_default()
 }
 
-#[derive(Debug, Clone)]
-pub struct Adventure {
-    pub date: String,
-    pub month: String,
-    pub year: String,
-    pub month_index: usize,
-    pub quarter: String,
-    pub title: String,
-    pub icon: String,
-    // pub location: String,
+pub struct AdventuresProps {
+    /// The entries from the sources `query` leaves on that match its other
+    /// filters, newest first.
+    pub entries: Vec<TimelineEntry>,
+    /// Every category and year among those sources' entries, with how many
+    /// entries have it.
+    pub categories: Vec<(TimelineCategory, usize)>,
+    pub years: Vec<(i32, usize)>,
+    pub query: TimelineQuery,
 }
 
-impl Adventure {
-    fn get_quarter(month: &str) -> String {
-        match month.to_lowercase().as_str() {
-            "january" | "february" | "march" => "Q1".to_string(),
-            "april" | "may" | "june" => "Q2".to_string(),
-            "july" | "august" | "september" => "Q3".to_string(),
-            "october" | "november" | "december" => "Q4".to_string(),
-            _ => "Q1".to_string(),
+impl AdventuresProps {
+    async fn load(query: TimelineQuery) -> Self {
+        let entries = merge_activity(load_timeline(), &query.sources()).await;
+        let categories = TimelineCategory::ALL
+            .into_iter()
+            .map(|category| {
+                let count = entries
+                    .iter()
+                    .filter(|entry| entry.category == category)
+                    .count();
+                (category, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        let mut years: Vec<(i32, usize)> = Vec::new();
+        for entry in &entries {
+            match years.last_mut() {
+                Some((year, count)) if *year == entry.date.year => *count += 1,
+                _ => years.push((entry.date.year, 1)),
+            }
+        }
+        Self {
+            entries: entries
+                .into_iter()
+                .filter(|entry| query.matches(entry))
+                .collect(),
+            categories,
+            years,
+            query,
         }
     }
+}
 
-    fn get_icon(title: &str) -> String {
-        let title_lower = title.to_lowercase();
-        if title_lower.contains("chakra") || title_lower.contains("ui") {
-            "fas fa-palette".to_string()
-        } else if title_lower.contains("rust") || title_lower.contains("ngyn") {
-            "fas fa-gears".to_string()
-        } else if title_lower.contains("web3") || title_lower.contains("blockchain") {
-            "fas fa-globe".to_string()
-        } else if title_lower.contains("community") || title_lower.contains("hangout") {
-            "fas fa-people-group".to_string()
-        } else if title_lower.contains("bot") || title_lower.contains("telegram") {
-            "fas fa-robot".to_string()
-        } else if title_lower.contains("app") || title_lower.contains("mobile") {
-            "fas fa-mobile-screen-button".to_string()
-        } else if title_lower.contains("framework") || title_lower.contains("library") {
-            "fas fa-screwdriver-wrench".to_string()
-        } else if title_lower.contains("game") || title_lower.contains("engine") {
-            "fas fa-gamepad".to_string()
-        } else if title_lower.contains("php") || title_lower.contains("laravel") {
-            "fas fa-lightbulb".to_string()
-        } else if title_lower.contains("open source") || title_lower.contains("pull request") {
-            "fas fa-code-branch".to_string()
-        } else if title_lower.contains("business") || title_lower.contains("sold") {
-            "fas fa-sack-dollar".to_string()
-        } else if title_lower.contains("programming") || title_lower.contains("html") {
-            "fas fa-seedling".to_string()
-        } else {
-            "fas fa-bolt".to_string()
-        }
+pub async fn adventures_handler(Query(query): Query<serde_json::Value>) -> impl IntoResponse {
+    let props = AdventuresProps::load(TimelineQuery::from_params(&query)).await;
+    Html(AdventuresPage::render(&props).to_string())
+}
+
+/// The timeline and its filters for the request's signals, replacing those
+/// on the page, with the address bar updated to match. The filters are
+/// replaced too, as switching a source changes the categories and years on
+/// offer.
+pub async fn filter_handler(Query(query): Query<serde_json::Value>) -> impl IntoResponse {
+    let filters = TimelineQuery::from_params(&PageQuery::signals(&query));
+    let href = filters.href();
+    let props = AdventuresProps::load(filters).await;
+
+    Sse::from_iter([
+        Event::from(
+            MergeFragments::new(Activity::render(&props))
+                .selector("#activity")
+                .mode(MergeMode::Outer),
+        ),
+        ExecuteScript::new(format!(
+            "history.replaceState(null, '', {})",
+            serde_json::Value::from(href)
+        ))
+        .into(),
+    ])
+}
+
+/// A `TimelineQuery` under the names its URL parameters and signals use.
+fn signals(query: &TimelineQuery) -> serde_json::Value {
+    let mut signals = serde_json::json!({
+        "category": query.category.map(TimelineCategory::as_str).unwrap_or_default(),
+        "year": query.year.map(|year| year.to_string()).unwrap_or_default(),
+        // Filter links reset it, as on the paged listings.
+        "cursor": "",
+    });
+    for source in TimelineSource::ALL {
+        signals[source.as_str()] = (!query.hidden.contains(&source)).into();
     }
+    signals
 }
 
-fn parse_adventures_from_json() -> Vec<Adventure> {
-    // This would normally read from a file, but for demo purposes, we'll use the embedded JSON
-    let json_data = include_str!("./adventures.json");
-
-    let data: AdventuresData = serde_json::from_str(json_data).unwrap_or_default();
-    let mut adventures = Vec::new();
-
-    for (year, months) in data.years {
-        for (month, activities) in months {
-            for (month_index, activity) in activities.into_iter().enumerate() {
-                let quarter = Adventure::get_quarter(&month);
-                adventures.push(Adventure {
-                    date: format!(
-                        "{} {}",
-                        month
-                            .chars()
-                            .nth(0)
-                            .unwrap()
-                            .to_uppercase()
-                            .collect::<String>()
-                            + &month[1..],
-                        year
-                    ),
-                    month: month.clone(),
-                    year: year.clone(),
-                    month_index,
-                    quarter,
-                    icon: Adventure::get_icon(&activity),
-                    // location: String::new(),
-                    title: activity,
-                });
-            }
-        }
+fn filters(query: &TimelineQuery) -> Filters {
+    Filters {
+        signals: signals(query),
+        endpoint: "/adventures/filter",
+        href: |params| TimelineQuery::from_params(params).href(),
     }
+}
 
-    // Sort by year and month (latest first)
-    adventures.sort_by(|a, b| {
-        let year_cmp = b.year.cmp(&a.year);
-        if year_cmp == std::cmp::Ordering::Equal {
-            let month_order = |m: &str| match m {
-                "january" => 1,
-                "february" => 2,
-                "march" => 3,
-                "april" => 4,
-                "may" => 5,
-                "june" => 6,
-                "july" => 7,
-                "august" => 8,
-                "september" => 9,
-                "october" => 10,
-                "november" => 11,
-                "december" => 12,
-                _ => 0,
-            };
-            let month_cmp = month_order(&b.month).cmp(&month_order(&a.month));
-            if month_cmp == std::cmp::Ordering::Equal {
-                b.month_index.cmp(&a.month_index)
-            } else {
-                month_cmp
-            }
-        } else {
-            year_cmp
-        }
-    });
+#[component]
+pub fn AdventuresPage(props: &AdventuresProps) -> Node {
+    rsx! {
+        <PageLayout title="Timeline" og_image={None} description={None} canonical={Some("/adventures".to_string())} meta={Vec::new()}>
+            <div data_signals={filters(&props.query).data_signals()}>
+                <section class="space-y-3 mb-10">
+                    <h1 class="text-4xl md:text-5xl font-semibold text-white">"Timeline"</h1>
+                    <div class="section-rule"></div>
+                    <p class="text-base text-zinc-300 max-w-3xl">
+                        "A decade of engineering milestones, product pivots, and experiments — with essays, releases and publications joining in as they go out."
+                    </p>
+                </section>
 
-    adventures
+                {Activity::render(props)}
+            </div>
+        </PageLayout>
+    }
+}
+
+#[component]
+fn Activity(
+    AdventuresProps {
+        entries,
+        categories,
+        years,
+        query,
+    }: &AdventuresProps,
+) -> Node {
+    let filters = filters(query);
+    let mut category_pills = vec![filters.pill("category", "", "Everything".to_string())];
+    category_pills.extend(categories.iter().map(|(category, count)| {
+        filters.pill(
+            "category",
+            category.as_str(),
+            format!("{} ({count})", category.label()),
+        )
+    }));
+    let mut year_pills = vec![filters.pill("year", "", "Any year".to_string())];
+    year_pills.extend(
+        years
+            .iter()
+            .map(|(year, _)| filters.pill("year", &year.to_string(), year.to_string())),
+    );
+    let navs = [
+        FilterNavProps {
+            label: "Show sources",
+            pills: TimelineSource::ALL
+                .into_iter()
+                .map(|source| filters.toggle(source.as_str(), source.label().to_string()))
+                .collect(),
+        },
+        FilterNavProps {
+            label: "Filter by category",
+            pills: category_pills,
+        },
+        FilterNavProps {
+            label: "Filter by year",
+            pills: year_pills,
+        },
+    ];
+
+    rsx! {
+        <div id="activity">
+            <div class="space-y-3 mb-10">
+                {navs.iter().map(FilterNav::render)}
+            </div>
+
+            <Timeline entries={entries.clone()} />
+        </div>
+    }
 }
 
-pub async fn adventures_handler() -> impl IntoResponse {
-    Html(AdventuresPage::render(&DefaultProps).to_string())
+pub struct TimelineProps {
+    pub entries: Vec<TimelineEntry>,
 }
 
 #[component]
-pub fn AdventuresPage() -> Node {
-    let adventures = parse_adventures_from_json();
-
-    // Collect unique years sorted descending
-    let mut years: Vec<String> = adventures
-        .iter()
-        .map(|a| a.year.clone())
-        .collect::<std::collections::HashSet<_>>()
-        .into_iter()
-        .collect();
-    years.sort_by(|a, b| b.cmp(a));
+fn Timeline(TimelineProps { entries }: &TimelineProps) -> Node {
+    let mut years: Vec<(i32, Vec<&TimelineEntry>)> = Vec::new();
+    for entry in entries {
+        match years.last_mut() {
+            Some((year, entries)) if *year == entry.date.year => entries.push(entry),
+            _ => years.push((entry.date.year, vec![entry])),
+        }
+    }
 
     rsx! {
-        <PageLayout title="Timeline">
-            <section class="space-y-3 mb-10">
-                <h1 class="text-4xl md:text-5xl font-semibold text-white">"Timeline"</h1>
-                <div class="section-rule"></div>
-                <p class="text-base text-zinc-300 max-w-3xl">
-                    "A decade of engineering milestones, product pivots, and experiments — in chronological order."
-                </p>
-            </section>
-
-            <div class="space-y-14">
-                {years.iter().map(|year| {
-                    let year_adventures: Vec<&Adventure> = adventures
-                        .iter()
-                        .filter(|a| &a.year == year)
-                        .collect();
-
-                    <div class="grid grid-cols-1 md:grid-cols-[100px_1fr] gap-4 md:gap-8">
-                        <div class="md:pt-1">
-                            <span class="text-4xl md:text-5xl font-bold text-white">{year.as_str()}</span>
-                        </div>
-                        <ul class="space-y-3">
-                            {year_adventures.iter().map(|adventure| {
-                                let is_major = adventure.title.len() > 60
-                                    || adventure.title.contains("Framework")
-                                    || adventure.title.contains("Joined")
-                                    || adventure.title.contains("Started work");
-
-                                <li class="flex items-start gap-3 group">
-                                    <i class={format!("{} mt-1 text-sm shrink-0 text-zinc-400 group-hover:text-zinc-200 transition-colors", adventure.icon)}></i>
-                                    <div class="space-y-0.5">
-                                        <div class={format!("text-sm md:text-base leading-snug {}",
-                                            if is_major { "text-zinc-100 font-medium" } else { "text-zinc-300" })}>
-                                            <div _dangerously_set_inner_html={markdown_to_html(&adventure.title, &Options::default())} />
-                                        </div>
-                                        <p class="text-xs" style="color: var(--accent); opacity: 0.6;">{adventure.date.as_str()}" · "{adventure.quarter.as_str()}</p>
-                                    </div>
-                                </li>
-                            })}
-                        </ul>
+        <div id="timeline" class="space-y-14">
+            {when!(years.is_empty() =>
+                <p class="text-sm text-zinc-500">"Nothing on the timeline matches these filters."</p>
+            )}
+            {years.iter().map(|(year, entries)| {
+                <div class="grid grid-cols-1 md:grid-cols-[100px_1fr] gap-4 md:gap-8">
+                    <div class="md:pt-1">
+                        <span class="text-4xl md:text-5xl font-bold text-white">{year.to_string()}</span>
                     </div>
-                })}
+                    <ul class="space-y-3">
+                        {entries.iter().map(|entry| TimelineItem::render(entry))}
+                    </ul>
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[component]
+fn TimelineItem(entry: &TimelineEntry) -> Node {
+    let is_major = entry.title.len() > 60
+        || entry.title.contains("Framework")
+        || entry.title.contains("Joined")
+        || entry.title.contains("Started work");
+    let dated = [
+        Some(entry.date.to_string()),
+        entry.date.quarter(),
+        entry.location.clone(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" · ");
+
+    rsx! {
+        <li class="flex items-start gap-3 group">
+            <i class={format!("{} mt-1 text-sm shrink-0 text-zinc-400 group-hover:text-zinc-200 transition-colors", escape_xml(entry.icon()))} title={entry.category.label()}></i>
+            <div class="space-y-1">
+                <div class={format!("text-sm md:text-base leading-snug {}",
+                    if is_major { "text-zinc-100 font-medium" } else { "text-zinc-300" })}>
+                    <div _dangerously_set_inner_html={markdown_to_html(&entry.title, &Options::default())} />
+                </div>
+                {when!(let Some(body) = &entry.body =>
+                    <div class="text-sm text-zinc-400 leading-relaxed" _dangerously_set_inner_html={markdown_to_html(body, &Options::default())} />
+                )}
+                {when!(!entry.images.is_empty() =>
+                    <div class="grid grid-cols-2 sm:grid-cols-3 gap-2 pt-1 max-w-xl">
+                        {entry.images.iter().map(|image| {
+                            <img
+                                src={escape_xml(&image.src)}
+                                alt={escape_xml(&image.alt)}
+                                loading="lazy"
+                                class="w-full rounded-lg border border-zinc-800"
+                            />
+                        })}
+                    </div>
+                )}
+                {when!(!entry.links.is_empty() =>
+                    <p class="flex flex-wrap gap-3 text-xs">
+                        {entry.links.iter().map(|link| {
+                            <a href={escape_xml(&link.url)} target="_blank" rel="noopener noreferrer" class="nav-link">
+                                <i class="fas fa-arrow-up-right-from-square mr-1"></i>{&link.label}
+                            </a>
+                        })}
+                    </p>
+                )}
+                <p class="text-xs" style="color: var(--accent); opacity: 0.6;">{dated}</p>
             </div>
-        </PageLayout>
+        </li>
     }
 }
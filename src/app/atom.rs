@@ -0,0 +1,92 @@
+use super::error::AppError;
+use super::resume::page::load_resume;
+use super::rss::feed_posts;
+use crate::shared::*;
+use axum::response::IntoResponse;
+use chrono::Utc;
+use momenta::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct AtomProps {
+    pub posts: Vec<Post>,
+    pub author: String,
+}
+
+impl AtomProps {
+    async fn load() -> Result<Self, AppError> {
+        Ok(Self {
+            posts: feed_posts().await?,
+            author: load_resume().header.name,
+        })
+    }
+}
+
+pub async fn atom_handler() -> Result<impl IntoResponse, AppError> {
+    let props = AtomProps::load().await?;
+    Ok((
+        [
+            ("Content-Type", "application/atom+xml; charset=utf-8"),
+            ("Cache-Control", "public, max-age=3600"),
+        ],
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>{}",
+            AtomPage::render(&props)
+        ),
+    ))
+}
+
+#[component]
+pub fn AtomPage(props: &AtomProps) -> Node {
+    use xml_elements as momenta;
+    let feed_updated = props
+        .posts
+        .iter()
+        .filter_map(Post::updated)
+        .max()
+        .unwrap_or_else(Utc::now)
+        .to_rfc3339();
+
+    rsx! {
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <id>{format!("{BASE_URL}/essays")}</id>
+            <title>"elcharitas.wtf — Essays"</title>
+            <subtitle>"Software development, product decisions, and the realities of shipping."</subtitle>
+            <link rel="alternate" type="text/html" href={format!("{BASE_URL}/essays")} />
+            <link rel="self" type="application/atom+xml" href={format!("{BASE_URL}/atom.xml")} />
+            <updated>{&feed_updated}</updated>
+            <icon>{format!("{BASE_URL}/icon.png")}</icon>
+            <author>
+                <name>{&props.author}</name>
+                <uri>{BASE_URL}</uri>
+            </author>
+            {props.posts.iter().map(|post| {
+                let updated = post.updated().map_or_else(|| feed_updated.clone(), |date| date.to_rfc3339());
+                let href = escape_xml(&post.url);
+                <entry>
+                    <id>{&post.url}</id>
+                    <title>{&post.title}</title>
+                    <link rel="alternate" type="text/html" href={href} />
+                    {when!(let Some(cover) = post.cover_image_url() =>
+                        <link rel="enclosure" type={image_mime_type(cover)} href={escape_xml(cover)} />
+                    )}
+                    {when!(let Some(published) = post.published() =>
+                        <published>{published.to_rfc3339()}</published>
+                    )}
+                    <updated>{updated}</updated>
+                    <author>
+                        <name>{&props.author}</name>
+                        <uri>{BASE_URL}</uri>
+                    </author>
+                    {post.tags.iter().map(|tag| {
+                        <category term={escape_xml(&tag.slug)} label={escape_xml(&tag.name)} />
+                    })}
+                    <summary type="text">{&post.brief}</summary>
+                    {when!(let Some(content) = &post.content =>
+                        <content type="html">{&content.html}</content>
+                    )}
+                </entry>
+            })}
+        </feed>
+    }
+}
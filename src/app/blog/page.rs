@@ -1,43 +1,165 @@
+use crate::app::error::AppError;
+use crate::components::card::ScrollCard;
+use crate::components::filter::{FilterNav, FilterNavProps, Filters};
 use crate::components::{PageLayout, article::Article};
+use crate::datastar::{Event, ExecuteScript, MergeFragments, MergeMode, MergeSignals, Sse};
 use crate::requests::fetch_all_posts;
 use crate::shared::*;
 use axum::{
     extract::Query,
     response::{Html, IntoResponse},
 };
+use futures::stream::{self, StreamExt};
 use momenta::prelude::*;
-use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+const POSTS_PER_PAGE: usize = 12;
+
 pub struct BlogProps {
     pub posts: Vec<Post>,
+    /// The first tag of every essay, for filter pills.
+    pub categories: Vec<String>,
+    pub query: PostQuery,
+    pub page: usize,
+    pub has_next_page: bool,
 }
 
 impl BlogProps {
-    async fn load() -> Self {
-        Self {
-            posts: fetch_all_posts().await,
+    async fn load(query: PostQuery, page: usize) -> Result<Self, AppError> {
+        let posts = fetch_all_posts().await?;
+        let mut categories: Vec<String> = Vec::new();
+        for category in posts.iter().filter_map(|post| post.tags.first()) {
+            if !categories.contains(&category.name) {
+                categories.push(category.name.clone());
+            }
+        }
+        let (posts, has_next_page) = paginate(posts, &query, page);
+        if posts.is_empty() && page > 1 {
+            return Err(AppError::NotFound);
         }
+        Ok(Self {
+            posts,
+            categories,
+            query,
+            page,
+            has_next_page,
+        })
     }
 }
 
-pub async fn blog_handler() -> impl IntoResponse {
-    let props = BlogProps::load().await;
-    Html(BlogPage::render(&props).to_string())
+/// The essays matching `query` on `page`, and whether more follow.
+fn paginate(posts: Vec<Post>, query: &PostQuery, page: usize) -> (Vec<Post>, bool) {
+    let mut matching = posts.into_iter().filter(|post| query.matches(post));
+    let posts: Vec<Post> = matching
+        .by_ref()
+        .skip((page - 1) * POSTS_PER_PAGE)
+        .take(POSTS_PER_PAGE)
+        .collect();
+    (posts, matching.next().is_some())
+}
+
+/// A page number counting from 1. One whose offset would overflow is
+/// refused, which also keeps the next page's number in range.
+fn parse_page(page: &str) -> Option<usize> {
+    page.parse::<usize>()
+        .ok()
+        .filter(|page| *page > 0 && page.checked_mul(POSTS_PER_PAGE).is_some())
+}
+
+/// `?page=N`, counting from 1.
+fn page_param(params: &serde_json::Value) -> Result<usize, AppError> {
+    let Some(page) = params.get("page").and_then(|page| page.as_str()) else {
+        return Ok(1);
+    };
+    parse_page(page).ok_or_else(|| AppError::BadRequest(format!("invalid page {page:?}")))
+}
+
+pub async fn blog_handler(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let page = page_param(&query)?;
+    let props = BlogProps::load(PostQuery::from_params(&query), page).await?;
+    Ok(Html(BlogPage::render(&props).to_string()))
+}
+
+/// A `PostQuery` under the names its URL parameters and signals use.
+fn signals(query: &PostQuery) -> serde_json::Value {
+    serde_json::json!({
+        "tag": query.tag.as_deref().unwrap_or_default(),
+        "q": query.search.as_deref().unwrap_or_default(),
+    })
+}
+
+/// `research-notes` as `Research Notes`.
+fn category_label(category: &str) -> String {
+    category
+        .split(['-', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[component]
-pub fn BlogPage(BlogProps { posts }: &BlogProps) -> Node {
-    let mut seen_cats = std::collections::HashSet::new();
-    let categories: Vec<String> = posts
-        .iter()
-        .filter_map(|p| p.tags.first().map(|t| t.name.clone()))
-        .filter(|c| seen_cats.insert(c.clone()))
-        .collect();
+pub fn BlogPage(
+    BlogProps {
+        posts,
+        categories,
+        query,
+        page,
+        has_next_page,
+    }: &BlogProps,
+) -> Node {
+    let mut signals = signals(query);
+    signals["cursor"] = match has_next_page {
+        true => (page + 1).to_string(),
+        false => String::new(),
+    }
+    .into();
+    signals["has_next_page"] = (*has_next_page).into();
+    let filters = Filters {
+        signals,
+        endpoint: "/essays/infinite_scroll",
+        href: |params| PostQuery::from_params(params).href(1),
+    };
+    let mut pills = vec![filters.pill("tag", "", "All".to_string())];
+    pills.extend(
+        categories
+            .iter()
+            .map(|category| filters.pill("tag", category, category_label(category))),
+    );
+    let tags = FilterNavProps {
+        label: "Filter by tag",
+        pills,
+    };
+    let search = escape_xml(query.search.as_deref().unwrap_or_default());
+    let tag = query.tag.as_deref().map(escape_xml);
+    let prev = (*page > 1).then(|| escape_xml(&query.href(page - 1)));
+    let next = has_next_page.then(|| escape_xml(&query.href(page + 1)));
+    // A tag's listing is its own page under the tag's slug, however the tag
+    // was spelled; a search is a view of the listing it narrows.
+    let canonical = PostQuery {
+        tag: query.tag.as_ref().and_then(|tag| {
+            posts
+                .iter()
+                .flat_map(|post| &post.tags)
+                .find(|candidate| {
+                    candidate.name.eq_ignore_ascii_case(tag)
+                        || candidate.slug.eq_ignore_ascii_case(tag)
+                })
+                .map(|candidate| candidate.slug.clone())
+        }),
+        search: None,
+    }
+    .href(*page);
 
     rsx! {
-        <PageLayout title="Essays">
-            <div class="py-4 md:py-8 space-y-8">
+        <PageLayout title="Essays" og_image={None} description={None} canonical={Some(canonical)} meta={Vec::new()}>
+            <div class="py-4 md:py-8 space-y-8" data_signals={filters.data_signals()}>
                 <section class="space-y-4">
                     <h1 class="text-4xl md:text-5xl font-semibold text-white">"Essays"</h1>
                     <div class="section-rule"></div>
@@ -47,66 +169,120 @@ pub fn BlogPage(BlogProps { posts }: &BlogProps) -> Node {
                 </section>
 
                 <div class="space-y-4">
-                    <div class="relative">
+                    <form action="/essays" method="get" role="search" class="relative" data_on_submit={filters.on_submit()}>
+                        {when!(let Some(tag) = &tag =>
+                            <input type="hidden" name="tag" value={tag.as_str()} />
+                        )}
                         <i class="fas fa-search absolute left-4 top-1/2 -translate-y-1/2 text-zinc-500 text-sm pointer-events-none"></i>
                         <input
                             id="search-input"
-                            type="text"
+                            type="search"
+                            name="q"
+                            value={search.as_str()}
+                            data_bind="q"
                             placeholder="Search essays..."
                             class="w-full bg-zinc-900/60 border border-zinc-800 rounded-xl pl-10 pr-4 py-3 text-sm text-zinc-200 placeholder-zinc-600 focus:outline-none focus:border-zinc-700 transition-colors"
                         />
-                    </div>
-                    <div class="flex flex-wrap gap-2">
-                        <button data_tag_filter="" class="text-xs px-3 py-1.5 rounded-full border border-zinc-700 text-zinc-400 hover:border-zinc-500 cursor-pointer transition-colors">"All"</button>
-                        {categories.iter().map(|cat| {
-                            <button data_tag_filter={cat.as_str()} class="text-xs px-3 py-1.5 rounded-full border border-zinc-700 text-zinc-500 hover:border-zinc-500 cursor-pointer transition-colors capitalize">{cat.replace('-', " ")}</button>
-                        })}
-                    </div>
+                    </form>
+                    <FilterNav {..tags} />
                 </div>
 
-                <div id="click_to_load_rows" class="grid grid-cols-1 md:grid-cols-2 xl:grid-cols-3 gap-4">
-                    {posts.into_iter().map(|post| {
-                        let tag_name = post.tags.first().map_or_else(String::new, |t| t.name.clone());
-                        let search_text = format!("{} {}", post.title, post.brief);
-                        <div data_searchtext={search_text.as_str()} data_tags={tag_name.as_str()}>
-                            <Article post={post.clone()} show_read_more />
-                        </div>
-                    })}
-                </div>
+                <EssayGrid posts={posts.clone()} />
+                <ScrollCard intersect="@get('/essays/infinite_scroll')" />
 
-                <script>{r#"
-                (function(){
-                  var q='',activeTag=null;
-                  function filter(){
-                    document.querySelectorAll('[data-searchtext]').forEach(function(el){
-                      var text=(el.getAttribute('data-searchtext')||'').toLowerCase();
-                      var tags=(el.getAttribute('data-tags')||'').toLowerCase();
-                      var ms=!q||text.includes(q);
-                      var mt=!activeTag||tags.split(',').some(function(t){return t.trim()===activeTag;});
-                      el.style.display=ms?(mt?'':'none'):'none';
-                    });
-                  }
-                  var s=document.getElementById('search-input');
-                  if(s)s.addEventListener('input',function(){q=s.value.toLowerCase().trim();filter();});
-                  function setPill(el){
-                    document.querySelectorAll('[data-tag-filter]').forEach(function(p){p.removeAttribute('style');});
-                    if(el)el.style.cssText='background:var(--accent-dim);border-color:var(--accent-border);color:var(--accent);';
-                  }
-                  document.querySelectorAll('[data-tag-filter]').forEach(function(pill){
-                    pill.addEventListener('click',function(){
-                      activeTag=pill.getAttribute('data-tag-filter')||null;
-                      setPill(pill);
-                      filter();
-                    });
-                  });
-                  setPill(document.querySelector('[data-tag-filter=""]'));
-                })();
-                "#}</script>
+                <nav class="flex justify-between text-sm" aria_label="Pages">
+                    {when!(let Some(prev) = &prev =>
+                        <a rel="prev" href={prev.as_str()} class="nav-link"><i class="fas fa-arrow-left mr-2"></i>"Newer essays"</a>
+                    )}
+                    // Scrolling loads the next page once scripts run.
+                    {when!(let Some(next) = &next =>
+                        <a rel="next" href={next.as_str()} class="nav-link ml-auto" data_show="false">"Older essays"<i class="fas fa-arrow-right ml-2"></i></a>
+                    )}
+                </nav>
             </div>
         </PageLayout>
     }
 }
 
-pub async fn infinite_scroll(Query(_query): Query<serde_json::Value>) -> impl IntoResponse {
-    ([("Content-Type", "text/event-stream")], String::new())
+pub struct EssayGridProps {
+    pub posts: Vec<Post>,
+}
+
+#[component]
+fn EssayGrid(EssayGridProps { posts }: &EssayGridProps) -> Node {
+    rsx! {
+        <div id="click_to_load_rows" class="grid grid-cols-1 md:grid-cols-2 xl:grid-cols-3 gap-4">
+            {when!(posts.is_empty() =>
+                <p class="col-span-full text-sm text-zinc-500">"No essays match these filters."</p>
+            )}
+            {posts.iter().map(|post| {
+                <div>
+                    <Article post={post.clone()} show_read_more />
+                </div>
+            })}
+        </div>
+    }
+}
+
+/// The next page of `/essays` for the filters in the request's signals. An
+/// empty cursor means the filters changed: the grid is replaced with their
+/// first page and the address bar updated to match.
+pub async fn infinite_scroll(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let filters = PostQuery::from_params(&PageQuery::signals(&query));
+    let PageQuery {
+        cursor,
+        has_next_page,
+    } = PageQuery::from_query(Query(query));
+
+    let reset = cursor.is_empty();
+    if !reset && !has_next_page {
+        return Ok(Sse::default());
+    }
+
+    let page: usize = match reset {
+        true => 1,
+        false => parse_page(&cursor)
+            .ok_or_else(|| AppError::BadRequest(format!("invalid cursor {cursor:?}")))?,
+    };
+    let (posts, has_next_page) = paginate(fetch_all_posts().await?, &filters, page);
+
+    let cursor = match has_next_page {
+        true => (page + 1).to_string(),
+        false => String::new(),
+    };
+    let signals = MergeSignals::new(serde_json::json!({
+        "cursor": cursor,
+        "has_next_page": has_next_page,
+    }));
+    if reset {
+        return Ok(Sse::from_iter([
+            Event::from(
+                MergeFragments::new(EssayGrid::render(&EssayGridProps { posts }))
+                    .selector("#click_to_load_rows")
+                    .mode(MergeMode::Outer),
+            ),
+            ExecuteScript::new(format!(
+                "history.replaceState(null, '', {})",
+                serde_json::Value::from(filters.href(1))
+            ))
+            .into(),
+            signals.into(),
+        ]));
+    }
+
+    // Each card is rendered as the response is written, so the first ones
+    // reach the page before the rest are ready.
+    let cards = stream::iter(posts).map(|post| {
+        MergeFragments::new(rsx! {
+            <div>
+                <Article post={post} show_read_more />
+            </div>
+        })
+        .selector("#click_to_load_rows")
+        .mode(MergeMode::Append)
+        .into()
+    });
+    Ok(Sse::new(cards.chain(stream::iter([signals.into()]))))
 }
@@ -1,28 +1,127 @@
 use crate::components::PageLayout;
+use crate::publications::PublicationsError;
+use crate::requests::{GitHubError, log_warning};
 use axum::{
-    http::StatusCode,
-    response::{Html, IntoResponse},
+    http::{StatusCode, header},
+    response::{Html, IntoResponse, Response},
 };
-use momenta::nodes::DefaultProps;
 use momenta::prelude::*;
 
+/// Errors a handler can surface to the visitor. Upstream failures that still
+/// have a last good response never get here; they are served stale instead.
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("not found")]
+    NotFound,
+    #[error("upstream unavailable: {0}")]
+    UpstreamUnavailable(String),
+    #[error("bad request: {0}")]
+    BadRequest(String),
+    /// A panic on the native server, or an error the worker itself raised.
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+impl From<GitHubError> for AppError {
+    fn from(error: GitHubError) -> Self {
+        if error.is_not_found() {
+            AppError::NotFound
+        } else {
+            AppError::UpstreamUnavailable(error.to_string())
+        }
+    }
+}
+
+impl From<PublicationsError> for AppError {
+    fn from(error: PublicationsError) -> Self {
+        AppError::UpstreamUnavailable(error.to_string())
+    }
+}
+
+impl AppError {
+    fn status(&self) -> StatusCode {
+        match self {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::UpstreamUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        if matches!(
+            self,
+            AppError::UpstreamUnavailable(_) | AppError::Internal(_)
+        ) {
+            log_warning(&self.to_string());
+        }
+
+        let status = self.status();
+        let page = Html(ErrorPage::render(&ErrorPageProps { status }).to_string());
+        match self {
+            AppError::UpstreamUnavailable(_) => {
+                (status, [(header::RETRY_AFTER, "60")], page).into_response()
+            }
+            _ => (status, page).into_response(),
+        }
+    }
+}
+
 pub async fn error_handler() -> impl IntoResponse {
-    (
-        StatusCode::NOT_FOUND,
-        Html(ErrorPage::render(&DefaultProps).to_string()),
-    )
+    AppError::NotFound
+}
+
+/// Renders the styled 500 page for a handler that panicked. Only the native
+/// server can catch panics; on the worker a panic aborts the isolate, so there
+/// only the router's own errors reach [`AppError::Internal`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn panic_handler(panic: Box<dyn std::any::Any + Send + 'static>) -> Response {
+    let message = match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => panic
+            .downcast_ref::<&str>()
+            .map_or("handler panicked", |message| message)
+            .to_string(),
+    };
+    AppError::Internal(message).into_response()
+}
+
+pub struct ErrorPageProps {
+    pub status: StatusCode,
 }
 
 #[component]
-pub fn ErrorPage() -> Node {
+pub fn ErrorPage(ErrorPageProps { status }: &ErrorPageProps) -> Node {
+    let (heading, message) = match *status {
+        StatusCode::NOT_FOUND => (
+            "Page Not Found",
+            "This page doesn't exist or has moved. Try one of these instead.",
+        ),
+        StatusCode::BAD_REQUEST => (
+            "Bad Request",
+            "That request didn't look quite right. Try one of these instead.",
+        ),
+        StatusCode::SERVICE_UNAVAILABLE => (
+            "Temporarily Unavailable",
+            "A service this page depends on isn't responding right now. Please try again in a minute.",
+        ),
+        _ => (
+            "Something Went Wrong",
+            "An unexpected error occurred while rendering this page. It has been logged.",
+        ),
+    };
+    let title = format!("{heading} - {}", status.as_u16());
+
     rsx! {
-        <PageLayout title="Page Not Found - 404">
+        <PageLayout title={title} og_image={None} description={None} canonical={None} meta={Vec::new()}>
             <div class="py-4 md:py-8 space-y-6">
                 <section class="space-y-3">
-                    <h1 class="text-4xl md:text-5xl font-semibold text-white">"Page Not Found"</h1>
+                    <h1 class="text-4xl md:text-5xl font-semibold text-white">{heading}</h1>
                     <div class="section-rule"></div>
                     <p class="text-base text-zinc-400 max-w-2xl">
-                        "This page doesn't exist or has moved. Try one of these instead."
+                        {message}
                     </p>
                 </section>
 
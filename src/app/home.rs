@@ -1,33 +1,55 @@
+use super::resume::page::load_resume;
 use crate::components::PageLayout;
+use crate::components::structured_data::{JsonLd, Person};
+use crate::overlay::load_overlay;
+use crate::requests::cached_project;
+use crate::shared::{Project, http_url};
 use axum::response::{Html, IntoResponse};
-use momenta::nodes::DefaultProps;
 use momenta::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize)]
-struct FeaturedProject {
-    name: String,
-    description: String,
-    url: String,
-    homepage: String,
-    tags: Vec<String>,
+#[derive(Serialize, Deserialize)]
+pub struct HomeProps {
+    /// The project `projects.json` features, if it names one.
+    pub featured: Option<Project>,
 }
 
-fn load_featured() -> FeaturedProject {
-    let json = include_str!("./featured.json");
-    serde_json::from_str(json).expect("featured.json is valid")
+impl HomeProps {
+    /// Reads the project from the cached catalogue, falling back to what the
+    /// overlay says about it, so the home page never waits on GitHub.
+    fn load() -> Self {
+        let overlay = load_overlay();
+        let featured = overlay.featured.as_deref().and_then(|name| {
+            cached_project(name).or_else(|| overlay.named(name).map(|entry| entry.to_project()))
+        });
+        Self { featured }
+    }
 }
 
 pub async fn home_handler() -> impl IntoResponse {
-    Html(HomePage::render(&DefaultProps).to_string())
+    let props = HomeProps::load();
+    Html(HomePage::render(&props).to_string())
 }
 
 #[component]
-pub fn HomePage() -> Node {
-    let featured = load_featured();
+pub fn HomePage(HomeProps { featured }: &HomeProps) -> Node {
+    let person = Person::from(&load_resume());
+    // The featured entry can describe a third-party repository, so its links
+    // are escaped and limited to http(s).
+    let source_url = featured.as_ref().and_then(|project| http_url(&project.url));
+    let homepage = featured
+        .as_ref()
+        .and_then(|project| http_url(&project.homepage));
+    let source_icon = match featured {
+        Some(project) if !project.url.starts_with("https://github.com/") => {
+            "fas fa-code-branch text-base"
+        }
+        _ => "fab fa-github text-base",
+    };
 
     rsx! {
-        <PageLayout title="Home">
+        <PageLayout title="Home" og_image={None} description={None} canonical={None} meta={Vec::new()}>
+            <JsonLd data={vec![person.into()]} />
             <section class="min-h-[70vh] flex items-center">
                 <div class="grid grid-cols-1 lg:grid-cols-[1.1fr_0.9fr] gap-8 lg:gap-12 w-full items-center">
                     <div class="space-y-6">
@@ -46,34 +68,34 @@ pub fn HomePage() -> Node {
                     </div>
 
                     <div class="space-y-4">
-                        <div class="border border-zinc-800/60 rounded-2xl p-5 bg-zinc-950/50 space-y-4">
-                            <p class="text-xs uppercase tracking-[0.1em] text-zinc-500">"Featured Project"</p>
-                            <div>
-                                <div class="flex items-start justify-between gap-3">
-                                    <p class="font-semibold text-zinc-100 text-base">{&featured.name}</p>
-                                    <div class="flex items-center gap-2 shrink-0">
-                                        <a href={&featured.url} target="_blank" rel="noopener noreferrer" class="social-link text-xs hover:text-zinc-300" aria_label="Source">
-                                            <i class="fab fa-github text-base"></i>
-                                        </a>
-                                        {if !featured.homepage.is_empty() {
-                                            rsx! {
-                                                <a href={&featured.homepage} target="_blank" rel="noopener noreferrer" class="social-link text-xs hover:text-zinc-300" aria_label="Website">
+                        {when!(let Some(featured) = featured =>
+                            <div class="border border-zinc-800/60 rounded-2xl p-5 bg-zinc-950/50 space-y-4">
+                                <p class="text-xs uppercase tracking-[0.1em] text-zinc-500">"Featured Project"</p>
+                                <div>
+                                    <div class="flex items-start justify-between gap-3">
+                                        <a href={format!("/projects/{}", featured.name)} class="font-semibold text-zinc-100 text-base hover:text-white">{&featured.name}</a>
+                                        <div class="flex items-center gap-2 shrink-0">
+                                            {when!(let Some(source_url) = &source_url =>
+                                                <a href={source_url.as_str()} target="_blank" rel="noopener noreferrer" class="social-link text-xs hover:text-zinc-300" aria_label="Source">
+                                                    <i class={source_icon}></i>
+                                                </a>
+                                            )}
+                                            {when!(let Some(homepage) = &homepage =>
+                                                <a href={homepage.as_str()} target="_blank" rel="noopener noreferrer" class="social-link text-xs hover:text-zinc-300" aria_label="Website">
                                                     <i class="fas fa-arrow-up-right-from-square text-sm"></i>
                                                 </a>
-                                            }
-                                        } else {
-                                            rsx! { <></> }
-                                        }}
+                                            )}
+                                        </div>
                                     </div>
+                                    <p class="mt-2 text-sm text-zinc-400 leading-relaxed">{&featured.description}</p>
+                                </div>
+                                <div class="flex flex-wrap gap-2">
+                                    {featured.tags.iter().map(|tag| {
+                                        <span class="text-xs px-2 py-0.5 rounded-full border border-zinc-700 text-zinc-400">{tag}</span>
+                                    })}
                                 </div>
-                                <p class="mt-2 text-sm text-zinc-400 leading-relaxed">{&featured.description}</p>
-                            </div>
-                            <div class="flex flex-wrap gap-2">
-                                {featured.tags.iter().map(|tag| {
-                                    <span class="text-xs px-2 py-0.5 rounded-full border border-zinc-700 text-zinc-400">{tag}</span>
-                                })}
                             </div>
-                        </div>
+                        )}
                         <div class="border border-zinc-800 rounded-2xl p-5 bg-zinc-950/60 space-y-3">
                         <p class="text-xs uppercase tracking-[0.1em] text-zinc-500 mb-3">"Now"</p>
                         <p class="text-sm text-zinc-300 leading-relaxed">"Building and writing in public. Subscribe to the newsletter for weekly updates on what ships next."</p>
@@ -0,0 +1,96 @@
+use super::error::AppError;
+use super::resume::page::load_resume;
+use super::rss::feed_posts;
+use crate::shared::*;
+use axum::response::IntoResponse;
+use serde::Serialize;
+
+/// A [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/) document.
+#[derive(Serialize)]
+pub struct JsonFeed {
+    pub version: &'static str,
+    pub title: String,
+    pub home_page_url: String,
+    pub feed_url: String,
+    pub description: String,
+    pub icon: String,
+    pub favicon: String,
+    pub language: &'static str,
+    pub authors: Vec<JsonFeedAuthor>,
+    pub items: Vec<JsonFeedItem>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct JsonFeedAuthor {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize)]
+pub struct JsonFeedItem {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_html: Option<String>,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_modified: Option<String>,
+    pub authors: Vec<JsonFeedAuthor>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl JsonFeed {
+    async fn load() -> Result<Self, AppError> {
+        let author = JsonFeedAuthor {
+            name: load_resume().header.name,
+            url: BASE_URL.to_string(),
+        };
+        let items = feed_posts()
+            .await?
+            .into_iter()
+            .map(|post| JsonFeedItem {
+                id: post.url.clone(),
+                content_html: post.content.as_ref().map(|content| content.html.clone()),
+                image: post.cover_image_url().map(str::to_string),
+                date_published: post.published().map(|date| date.to_rfc3339()),
+                date_modified: post.updated().map(|date| date.to_rfc3339()),
+                authors: vec![author.clone()],
+                tags: post.tags.iter().map(|tag| tag.name.clone()).collect(),
+                url: post.url,
+                title: post.title,
+                summary: post.brief,
+            })
+            .collect();
+
+        Ok(Self {
+            version: "https://jsonfeed.org/version/1.1",
+            title: "elcharitas.wtf — Essays".to_string(),
+            home_page_url: format!("{BASE_URL}/essays"),
+            feed_url: format!("{BASE_URL}/feed.json"),
+            description: "Software development, product decisions, and the realities of shipping."
+                .to_string(),
+            icon: format!("{BASE_URL}/icon.png"),
+            favicon: format!("{BASE_URL}/icon.png"),
+            language: "en-US",
+            authors: vec![author],
+            items,
+        })
+    }
+}
+
+pub async fn json_feed_handler() -> Result<impl IntoResponse, AppError> {
+    let feed = JsonFeed::load().await?;
+    Ok((
+        [
+            ("Content-Type", "application/feed+json; charset=utf-8"),
+            ("Cache-Control", "public, max-age=3600"),
+        ],
+        serde_json::to_string(&feed).unwrap_or_default(),
+    ))
+}
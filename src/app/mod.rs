@@ -1,4 +1,5 @@
 mod assets;
+mod atom;
 mod adventures {
     pub mod page;
 }
@@ -6,92 +7,54 @@ mod blog {
     pub mod page;
     pub mod slug;
 }
-mod error;
+pub mod error;
 mod home;
+mod json_feed;
 pub mod newsletter;
-mod projects;
-mod publications;
+pub mod og;
+mod projects {
+    pub mod cover;
+    pub mod page;
+    pub mod slug;
+    pub mod stats;
+}
+mod publications {
+    pub mod page;
+    pub mod slug;
+}
 mod resume {
     pub mod page;
 }
+pub mod routes;
 mod rss;
 mod sitemap;
+mod status;
 
-#[cfg(not(target_arch = "wasm32"))]
-use axum::routing::post;
-use axum::{Router, routing::get};
+use axum::Router;
+#[cfg(target_arch = "wasm32")]
+use axum::http::{Method, Request};
 #[cfg(target_arch = "wasm32")]
-use axum::{
-    extract::{Path, Query},
-    http::{Method, Request},
-    response::{IntoResponse, Response},
-};
+use axum::response::Response;
 
 pub fn create_router() -> Router {
-    let router = Router::new()
-        .route("/", get(home::home_handler))
-        .route("/resume", get(resume::page::resume_handler))
-        .route("/styles.css", get(assets::styles_handler))
-        .route("/icon.png", get(assets::favicon_handler))
-        .route("/og.png", get(assets::og_image_handler));
-
     #[cfg(not(target_arch = "wasm32"))]
-    let router = router
-        .route(
-            "/publications",
-            get(|| async { publications::publications_handler().await }),
-        )
-        .route("/essays", get(blog::page::blog_handler))
-        .route("/essays/infinite_scroll", get(blog::page::infinite_scroll))
-        .route("/essays/{slug}", get(blog::slug::blog_detail_handler))
-        .route("/projects", get(projects::projects_handler))
-        .route("/projects/infinite_scroll", get(projects::infinite_scroll))
-        .route("/adventures", get(adventures::page::adventures_handler))
-        .route("/newsletter", get(newsletter::newsletter_get_handler))
-        .route("/newsletter", post(newsletter::newsletter_post_handler))
-        .route("/newsletter/send", post(newsletter::newsletter_send_handler))
-        .route("/rss.xml", get(rss::rss_handler))
-        .route("/sitemap.xml", get(sitemap::sitemap_handler))
-        .route(
-            "/blog",
-            get(|| async { axum::response::Redirect::permanent("/essays") }),
-        );
+    let router = routes::register(Router::new()).layer(
+        tower_http::catch_panic::CatchPanicLayer::custom(error::panic_handler),
+    );
 
+    // On wasm every route is served by `wasm_dynamic_response`; the router only
+    // supplies the 404 fallback.
     #[cfg(target_arch = "wasm32")]
-    let router = router.route(
-        "/blog",
-        get(|| async { axum::response::Redirect::permanent("/") }),
-    );
+    let router = Router::new();
 
-    router
-        .route(
-            "/mods/resume",
-            get(|| async { axum::response::Redirect::permanent("/resume") }),
-        )
-        .route(
-            "/mods/connect",
-            get(|| async { axum::response::Redirect::permanent("/connect") }),
-        )
-        .route(
-            "/connect",
-            get(|| async { axum::response::Redirect::temporary("https://cal.com/elcharitas") }),
-        )
-        .route(
-            "/resume.docx",
-            get(|| async {
-                axum::response::Redirect::temporary(
-                    "https://docs.google.com/document/d/e/2PACX-1vRxPZLfm3NkCml3MhdrnEIBjTBzhRvDznOyDYF19SGUlMaijecJ8oAtcFI-dnp49vC3Ndt2NJvlssNA/pub?embedded=true",
-                )
-            }),
-        )
-        .fallback(error::error_handler)
+    router.fallback(error::error_handler)
 }
 
 #[cfg(target_arch = "wasm32")]
 pub async fn wasm_dynamic_response<B>(req: &Request<B>) -> Option<Response> {
-    let method = req.method().clone();
-    let path = req.uri().path().to_string();
-    let headers = req.headers().clone();
+    if req.method() != Method::GET {
+        return None;
+    }
 
     let mut query_map = serde_json::Map::new();
     if let Some(query) = req.uri().query() {
@@ -99,54 +62,11 @@ pub async fn wasm_dynamic_response<B>(req: &Request<B>) -> Option<Response> {
             query_map.insert(k.to_string(), serde_json::Value::String(v.to_string()));
         }
     }
-    let query_value = serde_json::Value::Object(query_map);
-
-    if method == Method::GET && path == "/essays" {
-        return Some(blog::page::blog_handler().await.into_response());
-    }
-    if method == Method::GET && path == "/essays/infinite_scroll" {
-        return Some(
-            blog::page::infinite_scroll(Query(query_value))
-                .await
-                .into_response(),
-        );
-    }
-    if method == Method::GET && path.starts_with("/essays/") {
-        let slug = path.trim_start_matches("/essays/").to_string();
-        return Some(
-            blog::slug::blog_detail_handler(Path(crate::shared::PageParams { slug }), headers)
-                .await
-                .into_response(),
-        );
-    }
-
-    if method == Method::GET && path == "/projects" {
-        return Some(projects::projects_handler().await.into_response());
-    }
-    if method == Method::GET && path == "/projects/infinite_scroll" {
-        return Some(
-            projects::infinite_scroll(Query(query_value))
-                .await
-                .into_response(),
-        );
-    }
-
-    if method == Method::GET && path == "/publications" {
-        return Some(publications::publications_handler().await.into_response());
-    }
-
-    if method == Method::GET && path == "/adventures" {
-        return Some(adventures::page::adventures_handler().await.into_response());
-    }
-    if method == Method::GET && path == "/newsletter" {
-        return Some(newsletter::newsletter_get_handler().await.into_response());
-    }
-    if method == Method::GET && path == "/rss.xml" {
-        return Some(rss::rss_handler().await.into_response());
-    }
-    if method == Method::GET && path == "/sitemap.xml" {
-        return Some(sitemap::sitemap_handler().await.into_response());
-    }
 
-    None
+    routes::dispatch(
+        req.uri().path(),
+        req.headers().clone(),
+        serde_json::Value::Object(query_map),
+    )
+    .await
 }
@@ -167,24 +167,24 @@ fn render_welcome_email() -> String {
 #[cfg(target_arch = "wasm32")]
 async fn send_welcome_email(email: &str, api_key: &str) {
     let html = render_welcome_email();
-    let client = reqwest::Client::new();
     let payload = serde_json::json!({
         "from": from_data(),
         "to": [email],
         "subject": "Welcome to the newsletter",
         "html": html,
     });
-    match client
-        .post("https://api.resend.com/emails")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .json(&payload)
-        .send()
+    match crate::http::client()
+        .send(
+            crate::http::HttpRequest::post("https://api.resend.com/emails")
+                .header("Authorization", format!("Bearer {}", api_key))
+                .json(&payload),
+        )
         .await
     {
         Ok(resp) => worker::console_log!(
             "newsletter: welcome email sent to {} (status {})",
             email,
-            resp.status().as_u16()
+            resp.status
         ),
         Err(e) => worker::console_log!(
             "newsletter: failed to send welcome email to {}: {:?}",
@@ -219,7 +219,13 @@ pub async fn send_newsletter() {
         return;
     }
 
-    let posts = crate::requests::fetch_all_posts().await;
+    let posts = match crate::requests::fetch_all_posts().await {
+        Ok(posts) => posts,
+        Err(e) => {
+            worker::console_log!("newsletter: failed to load posts: {}", e);
+            return;
+        }
+    };
     let one_week_ago = chrono::Utc::now() - chrono::Duration::days(7);
     let recent: Vec<_> = posts
         .iter()
@@ -242,24 +248,10 @@ pub async fn send_newsletter() {
 
     let content_html = crate::requests::fetch_post_by_slug_from_github(&latest.slug)
         .await
+        .ok()
+        .flatten()
         .and_then(|p| p.content)
-        .map(|c| {
-            let opts = comrak::Options {
-                render: comrak::RenderOptions {
-                    unsafe_: true,
-                    ..Default::default()
-                },
-                extension: comrak::ExtensionOptions {
-                    table: true,
-                    strikethrough: true,
-                    autolink: true,
-                    tasklist: true,
-                    ..Default::default()
-                },
-                ..Default::default()
-            };
-            comrak::markdown_to_html(&c.markdown, &opts)
-        })
+        .map(|c| crate::shared::render_markdown(&c.markdown))
         .unwrap_or_default();
 
     let intro = if count == 1 {
@@ -279,7 +271,7 @@ pub async fn send_newsletter() {
         &more_posts,
     );
     let subject = format!("Weekly: {}", latest.title);
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     for email in &emails {
         let payload = serde_json::json!({
@@ -289,10 +281,11 @@ pub async fn send_newsletter() {
             "html": html,
         });
         let _ = client
-            .post("https://api.resend.com/emails")
-            .header("Authorization", format!("Bearer {}", api_key))
-            .json(&payload)
-            .send()
+            .send(
+                crate::http::HttpRequest::post("https://api.resend.com/emails")
+                    .header("Authorization", format!("Bearer {}", api_key))
+                    .json(&payload),
+            )
             .await;
     }
 }
@@ -300,7 +293,7 @@ pub async fn send_newsletter() {
 #[component]
 pub fn NewsletterPage(props: &NewsletterSubscription) -> Node {
     rsx! {
-        <PageLayout title="Newsletter">
+        <PageLayout title="Newsletter" og_image={None} description={None} canonical={None} meta={Vec::new()}>
             <div class="py-4 md:py-8 space-y-8">
                 {when!(props.email.is_empty() =>
                     <>
@@ -0,0 +1,254 @@
+use super::assets::og_image_handler;
+use super::error::AppError;
+use crate::publications::{fetch_publications, normalize_orcid_id};
+use crate::requests::{fetch_all_posts, get_project};
+use crate::shared::{BASE_URL, escape_xml, get_env};
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use resvg::{tiny_skia, usvg};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+const TITLE_LINE_CHARS: usize = 30;
+const TITLE_MAX_LINES: usize = 3;
+const TITLE_LINE_HEIGHT: usize = 76;
+const CACHE_CAPACITY: usize = 128;
+
+lazy_static::lazy_static! {
+    /// Rendered cards by SVG hash, least recently used first.
+    static ref OG_CACHE: std::sync::Mutex<VecDeque<(u64, Vec<u8>)>> =
+        std::sync::Mutex::new(VecDeque::new());
+}
+
+#[derive(Deserialize)]
+pub struct OgParams {
+    pub kind: String,
+    pub file: String,
+}
+
+/// Absolute URL of the generated share card for a piece of content.
+pub fn og_image_url(kind: &str, slug: &str) -> String {
+    format!("{BASE_URL}/og/{kind}/{slug}.png")
+}
+
+pub async fn og_card_handler(
+    Path(OgParams { kind, file }): Path<OgParams>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(slug) = file.strip_suffix(".png") else {
+        return AppError::NotFound.into_response();
+    };
+    if !matches!(kind.as_str(), "essays" | "projects" | "publications") {
+        return AppError::NotFound.into_response();
+    }
+
+    let Some(card) = OgCard::load(&kind, slug).await else {
+        return og_image_handler().await.into_response();
+    };
+
+    let svg = card.to_svg();
+    let mut hasher = DefaultHasher::new();
+    svg.hash(&mut hasher);
+    let hash = hasher.finish();
+    let etag = format!("\"{hash:x}\"");
+
+    if matches_etag(&headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    let png = match cached_png(hash) {
+        Some(png) => png,
+        None => {
+            let Some(png) = render_png(&svg) else {
+                return og_image_handler().await.into_response();
+            };
+            if let Ok(mut cache) = OG_CACHE.lock() {
+                if cache.len() >= CACHE_CAPACITY {
+                    cache.pop_front();
+                }
+                cache.push_back((hash, png.clone()));
+            }
+            png
+        }
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, "image/png".to_string()),
+            (header::CACHE_CONTROL, "public, max-age=86400".to_string()),
+            (header::ETAG, etag),
+        ],
+        png,
+    )
+        .into_response()
+}
+
+/// The cached card for `hash`, moved to the back as the most recently used.
+fn cached_png(hash: u64) -> Option<Vec<u8>> {
+    let mut cache = OG_CACHE.lock().ok()?;
+    let index = cache.iter().position(|(cached, _)| *cached == hash)?;
+    let entry = cache.remove(index)?;
+    let png = entry.1.clone();
+    cache.push_back(entry);
+    Some(png)
+}
+
+/// Whether the request's `If-None-Match` lists `etag`, weakly compared.
+fn matches_etag(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+struct OgCard {
+    kind: &'static str,
+    title: String,
+    category: String,
+    meta: Vec<String>,
+}
+
+impl OgCard {
+    async fn load(kind: &str, slug: &str) -> Option<Self> {
+        match kind {
+            "essays" => {
+                let post = fetch_all_posts()
+                    .await
+                    .ok()?
+                    .into_iter()
+                    .find(|post| post.slug == slug)?;
+                let mut meta = Vec::new();
+                if let Some(published_at) = &post.published_at {
+                    meta.push(format_date(published_at));
+                }
+                meta.push(format!("{} min read", post.read_time_in_minutes));
+                Some(Self {
+                    kind: "Essay",
+                    category: post
+                        .tags
+                        .first()
+                        .map_or_else(|| "general".to_string(), |tag| tag.name.clone()),
+                    title: post.title,
+                    meta,
+                })
+            }
+            "projects" => {
+                let project = get_project(slug).await.ok().flatten()?;
+                Some(Self {
+                    kind: "Project",
+                    category: project
+                        .language
+                        .clone()
+                        .or_else(|| project.tags.first().cloned())
+                        .unwrap_or_else(|| "open source".to_string()),
+                    meta: vec![
+                        format!("{} stars", project.stargazers_count),
+                        format!("Updated {}", format_date(&project.updated_at)),
+                    ],
+                    title: project.name,
+                })
+            }
+            "publications" => {
+                let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+                let publication = fetch_publications(&orcid_id)
+                    .await
+                    .ok()?
+                    .into_iter()
+                    .find(|publication| publication.id == slug)?;
+                Some(Self {
+                    kind: "Publication",
+                    category: publication
+                        .work_type
+                        .unwrap_or_else(|| "publication".to_string()),
+                    meta: publication
+                        .published_on
+                        .iter()
+                        .map(|date| format_date(date))
+                        .chain(publication.venue)
+                        .collect(),
+                    title: publication.title,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn to_svg(&self) -> String {
+        let lines = wrap_title(&self.title);
+        let title_y = 320 - (lines.len() * TITLE_LINE_HEIGHT) / 2 + 56;
+        let title_lines: String = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let dy = if index == 0 { 0 } else { TITLE_LINE_HEIGHT };
+                format!(r#"<tspan x="88" dy="{dy}">{}</tspan>"#, escape_xml(line))
+            })
+            .collect();
+        let category = self.category.replace('-', " ").to_uppercase();
+
+        include_str!("../images/og-card.svg")
+            .replace("{{kind}}", self.kind)
+            .replace(
+                "{{category_width}}",
+                &(category.chars().count() * 15 + 40).to_string(),
+            )
+            .replace("{{category}}", &escape_xml(&category))
+            .replace("{{title_y}}", &title_y.to_string())
+            .replace("{{title_lines}}", &title_lines)
+            .replace("{{meta}}", &escape_xml(&self.meta.join("  ·  ")))
+    }
+}
+
+fn render_png(svg: &str) -> Option<Vec<u8>> {
+    let mut options = usvg::Options::default();
+    options
+        .fontdb_mut()
+        .load_font_data(include_bytes!("../../public/fonts/CalSans-SemiBold.ttf").to_vec());
+
+    let tree = usvg::Tree::from_str(svg, &options).ok()?;
+    let size = tree.size().to_int_size();
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().ok()
+}
+
+fn wrap_title(title: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in title.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + word.chars().count() >= TITLE_LINE_CHARS
+        {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if lines.len() > TITLE_MAX_LINES {
+        lines.truncate(TITLE_MAX_LINES);
+        if let Some(last) = lines.last_mut() {
+            last.push('…');
+        }
+    }
+    lines
+}
+
+fn format_date(date: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .map(|date| date.date_naive())
+        .or_else(|_| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+        .map(|date| date.format("%b %d, %Y").to_string())
+        .unwrap_or_else(|_| date.to_string())
+}
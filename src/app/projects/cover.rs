@@ -0,0 +1,70 @@
+use crate::shared::PageParams;
+use crate::shared::escape_xml;
+use axum::{extract::Path, http::header, response::IntoResponse};
+
+const WIDTH: u32 = 1280;
+const HEIGHT: u32 = 640;
+/// Identicon cells per side; the left half is mirrored onto the right.
+const GRID: u64 = 5;
+const CELL: u64 = 64;
+
+pub async fn cover_handler(Path(PageParams { slug }): Path<PageParams>) -> impl IntoResponse {
+    (
+        [
+            (header::CONTENT_TYPE, "image/svg+xml"),
+            (header::CACHE_CONTROL, "public, max-age=604800"),
+        ],
+        cover_svg(&slug),
+    )
+}
+
+/// A social-preview-sized cover for a repository without an image of its
+/// own: a mirrored identicon and the name, coloured by a hash of the name so
+/// each project always gets the same one.
+fn cover_svg(name: &str) -> String {
+    let hash = fnv1a(name);
+    let hue = hash % 360;
+    // Bits above the hue pick the cells of the left half and middle column.
+    let pattern = hash >> 9;
+    let half = GRID.div_ceil(2);
+    let grid_size = GRID * CELL;
+    let grid_x = (u64::from(HEIGHT) - grid_size) / 2;
+    let grid_y = grid_x;
+
+    let mut cells = String::new();
+    for row in 0..GRID {
+        for column in 0..half {
+            if pattern >> (row * half + column) & 1 == 0 {
+                continue;
+            }
+            for x in [column, GRID - 1 - column] {
+                cells.push_str(&format!(
+                    r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}"/>"#,
+                    grid_x + x * CELL,
+                    grid_y + row * CELL,
+                ));
+                if x == GRID - 1 - x {
+                    break;
+                }
+            }
+        }
+    }
+
+    // Shrink long names to fit beside the identicon.
+    let text_x = grid_x * 2 + grid_size;
+    let room = u64::from(WIDTH) - text_x - grid_x;
+    let font_size = (room as f32 / (name.chars().count().max(1) as f32 * 0.6)).min(88.0);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}"><title>{title}</title><rect width="{WIDTH}" height="{HEIGHT}" fill="hsl({hue}, 30%, 10%)"/><g fill="hsl({hue}, 70%, 60%)">{cells}</g><text x="{text_x}" y="{middle}" fill="#fafafa" font-family="'Cal Sans', Inter, system-ui, sans-serif" font-size="{font_size:.0}" font-weight="600" dominant-baseline="middle">{title}</text></svg>"##,
+        title = escape_xml(name),
+        middle = HEIGHT / 2,
+    )
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is stable across Rust releases, so a
+/// cover doesn't change when the toolchain does.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,263 @@
+use crate::app::error::AppError;
+use crate::app::resume::page::load_resume;
+use crate::components::card::ScrollCard;
+use crate::components::filter::{FilterNav, FilterNavProps, Filters};
+use crate::components::structured_data::{JsonLd, Person, SoftwareSourceCode};
+use crate::components::{PageLayout, article::ProjectArticle};
+use crate::datastar::{Event, ExecuteScript, MergeFragments, MergeMode, MergeSignals, Sse};
+use crate::requests::{
+    ProjectCursor, ProjectFacets, get_project_facets, query_projects, with_activity,
+};
+use crate::shared::escape_xml;
+use crate::shared::{PageQuery, Project, ProjectQuery, ProjectSort};
+use axum::{
+    extract::Query,
+    response::{Html, IntoResponse},
+};
+use futures::stream::{self, StreamExt};
+use momenta::prelude::*;
+
+/// The next page of `/projects` for the filters in the request's signals. An
+/// empty cursor means the filters changed: the grid is replaced with their
+/// first page and the address bar updated to match.
+pub async fn infinite_scroll(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let filters = ProjectQuery::from_params(&PageQuery::signals(&query));
+    let PageQuery {
+        cursor,
+        has_next_page,
+    } = PageQuery::from_query(Query(query));
+
+    let reset = cursor.is_empty();
+    if !reset && !has_next_page {
+        return Ok(Sse::default());
+    }
+
+    let position = match reset {
+        true => ProjectCursor::default(),
+        false => ProjectCursor::parse(&cursor)
+            .ok_or_else(|| AppError::BadRequest(format!("invalid cursor {cursor:?}")))?,
+    };
+    let page = query_projects(&filters, position).await?;
+    let projects = with_activity(page.projects).await;
+    let next = page.next.map(|next| next.encode());
+
+    let signals = MergeSignals::new(serde_json::json!({
+        "cursor": next.as_deref().unwrap_or_default(),
+        "has_next_page": next.is_some(),
+    }));
+    if reset {
+        return Ok(Sse::from_iter([
+            Event::from(
+                MergeFragments::new(ProjectGrid::render(&ProjectGridProps { projects }))
+                    .selector("#click_to_load_rows")
+                    .mode(MergeMode::Outer),
+            ),
+            ExecuteScript::new(format!(
+                "history.replaceState(null, '', {})",
+                serde_json::Value::from(filters.href())
+            ))
+            .into(),
+            signals.into(),
+        ]));
+    }
+
+    // Each card is rendered as the response is written, so the first ones
+    // reach the page before the rest are ready.
+    let cards = stream::iter(projects).map(|project| {
+        MergeFragments::new(rsx! {
+            <div class="h-full">
+                <ProjectArticle {..project} />
+            </div>
+        })
+        .selector("#click_to_load_rows")
+        .mode(MergeMode::Append)
+        .into()
+    });
+    Ok(Sse::new(cards.chain(stream::iter([signals.into()]))))
+}
+
+pub struct ProjectGridProps {
+    pub projects: Vec<Project>,
+}
+
+#[component]
+fn ProjectGrid(ProjectGridProps { projects }: &ProjectGridProps) -> Node {
+    rsx! {
+        <div id="click_to_load_rows" class="grid grid-cols-1 md:grid-cols-2 xl:grid-cols-3 gap-4">
+            {when!(projects.is_empty() =>
+                <p class="col-span-full text-sm text-zinc-500">"No projects match these filters."</p>
+            )}
+            {projects.iter().map(|project| {
+                <div class="h-full">
+                    <ProjectArticle {..project.clone()} />
+                </div>
+            })}
+        </div>
+    }
+}
+
+pub struct ProjectsProps {
+    pub projects: Vec<Project>,
+    pub next: Option<ProjectCursor>,
+    pub facets: ProjectFacets,
+    pub query: ProjectQuery,
+}
+
+impl ProjectsProps {
+    async fn load(query: ProjectQuery) -> Result<Self, AppError> {
+        let (page, facets) = futures::join!(
+            query_projects(&query, ProjectCursor::default()),
+            get_project_facets()
+        );
+        let page = page?;
+        Ok(Self {
+            projects: with_activity(page.projects).await,
+            next: page.next,
+            facets: facets?,
+            query,
+        })
+    }
+}
+
+/// A `ProjectQuery` under the names its URL parameters and signals use.
+#[derive(serde::Serialize)]
+struct SignalQuery<'a> {
+    sort: &'a str,
+    tag: &'a str,
+    lang: &'a str,
+    group: &'a str,
+    q: &'a str,
+}
+
+impl<'a> From<&'a ProjectQuery> for SignalQuery<'a> {
+    fn from(query: &'a ProjectQuery) -> Self {
+        Self {
+            sort: query.sort.as_str(),
+            tag: query.tag.as_deref().unwrap_or_default(),
+            lang: query.language.as_deref().unwrap_or_default(),
+            group: query.group.as_deref().unwrap_or_default(),
+            q: query.search.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+pub async fn projects_handler(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let props = ProjectsProps::load(ProjectQuery::from_params(&query)).await?;
+    Ok(Html(ProjectsPage::render(&props).to_string()))
+}
+
+#[component]
+pub fn ProjectsPage(
+    ProjectsProps {
+        projects,
+        next,
+        facets,
+        query,
+    }: &ProjectsProps,
+) -> Node {
+    let mut signals = serde_json::to_value(SignalQuery::from(query)).unwrap_or_default();
+    signals["cursor"] = next.map(|next| next.encode()).unwrap_or_default().into();
+    signals["has_next_page"] = next.is_some().into();
+    let filters = Filters {
+        signals,
+        endpoint: "/projects/infinite_scroll",
+        href: |params| ProjectQuery::from_params(params).href(),
+    };
+    let facet_pills = |signal: &str, every: &str, values: &[(String, usize)], limit: usize| {
+        let mut pills = vec![filters.pill(signal, "", every.to_string())];
+        pills.extend(values.iter().take(limit).map(|(value, count)| {
+            let label = match signal {
+                "tag" => value.replace('-', " "),
+                _ => format!("{value} ({count})"),
+            };
+            filters.pill(signal, value, label)
+        }));
+        pills
+    };
+    let navs = [
+        FilterNavProps {
+            label: "Sort projects",
+            pills: ProjectSort::ALL
+                .iter()
+                .map(|sort| filters.pill("sort", sort.as_str(), sort.label().to_string()))
+                .collect(),
+        },
+        FilterNavProps {
+            label: "Filter by group",
+            pills: facet_pills("group", "Every group", &facets.groups, usize::MAX),
+        },
+        FilterNavProps {
+            label: "Filter by tag",
+            pills: facet_pills("tag", "All", &facets.tags, 20),
+        },
+        FilterNavProps {
+            label: "Filter by language",
+            pills: facet_pills("lang", "Any language", &facets.languages, 12),
+        },
+    ];
+    let search = escape_xml(query.search.as_deref().unwrap_or_default());
+    // Filters other than the search, so submitting it without scripts keeps them.
+    let kept: Vec<(&str, String)> = [
+        ("tag", query.tag.clone()),
+        ("lang", query.language.clone()),
+        ("group", query.group.clone()),
+        (
+            "sort",
+            (query.sort != ProjectSort::default()).then(|| query.sort.as_str().to_string()),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| Some((name, escape_xml(&value?))))
+    .collect();
+    let author = Person::from(&load_resume());
+    let structured_data: Vec<_> = projects
+        .iter()
+        .map(|project| SoftwareSourceCode::new(project, author.clone()).into())
+        .collect();
+
+    rsx! {
+        <PageLayout title="Projects" og_image={None} description={None} canonical={Some("/projects".to_string())} meta={Vec::new()}>
+            <JsonLd data={structured_data} />
+            <div class="py-4 md:py-8 space-y-8" data_signals={filters.data_signals()}>
+                <section class="space-y-4">
+                    <h1 class="text-4xl md:text-5xl font-semibold text-white">"Projects"</h1>
+                    <div class="section-rule"></div>
+                    <p class="text-base text-zinc-300 max-w-3xl">
+                        "Open-source contributions and personal experiments across backend systems, tools, and product prototypes. "
+                    </p>
+                    <a href="/projects/stats" class="inline-flex items-center gap-2 text-sm" style="color: var(--accent);">
+                        <i class="fas fa-chart-simple text-xs"></i>
+                        <span>"Languages and dependencies across these projects"</span>
+                    </a>
+                </section>
+
+                <div class="space-y-4">
+                    {FilterNav::render(&navs[0])}
+                    <form action="/projects" method="get" role="search" class="relative" data_on_submit={filters.on_submit()}>
+                        {kept.iter().map(|(name, value)| {
+                            <input type="hidden" name={*name} value={value.as_str()} />
+                        })}
+                        <i class="fas fa-search absolute left-4 top-1/2 -translate-y-1/2 text-zinc-500 text-sm pointer-events-none"></i>
+                        <input
+                            id="search-input"
+                            type="search"
+                            name="q"
+                            value={search.as_str()}
+                            data_bind="q"
+                            placeholder="Search projects..."
+                            class="w-full bg-zinc-900/60 border border-zinc-800 rounded-xl pl-10 pr-4 py-3 text-sm text-zinc-200 placeholder-zinc-600 focus:outline-none focus:border-zinc-700 transition-colors"
+                        />
+                    </form>
+                    {navs[1..].iter().map(FilterNav::render)}
+                </div>
+
+                <ProjectGrid projects={projects.clone()} />
+                <ScrollCard intersect="@get('/projects/infinite_scroll')" />
+            </div>
+        </PageLayout>
+    }
+}
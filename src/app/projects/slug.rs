@@ -0,0 +1,277 @@
+use crate::app::error::AppError;
+use crate::app::og::og_image_url;
+use crate::app::resume::page::load_resume;
+use crate::components::PageLayout;
+use crate::components::article::Article;
+use crate::components::structured_data::{BreadcrumbList, JsonLd, Person, SoftwareSourceCode};
+use crate::requests::{Readme, fetch_all_posts, get_project, get_project_readme};
+use crate::shared::*;
+use axum::{
+    extract::Path,
+    response::{Html, IntoResponse},
+};
+use momenta::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ProjectDetailProps {
+    pub project: Project,
+    /// The rendered README, with relative links pointing back at the repo.
+    pub readme_html: Option<String>,
+    /// Essays whose title, brief or tags name the project.
+    pub essays: Vec<Post>,
+}
+
+impl ProjectDetailProps {
+    async fn load(name: String) -> Result<Self, AppError> {
+        let project = get_project(&name).await?.ok_or(AppError::NotFound)?;
+        // The README and essays only add to the page, so it still renders
+        // from the project alone when either is unreachable.
+        let (readme, posts) = futures::join!(get_project_readme(&project), fetch_all_posts());
+        let readme_html = readme.ok().flatten().map(|readme| render_readme(&readme));
+        let essays = posts
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|post| mentions(post, &project.name))
+            .collect();
+        Ok(Self {
+            project,
+            readme_html,
+            essays,
+        })
+    }
+}
+
+pub async fn project_detail_handler(
+    Path(params): Path<PageParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let props = ProjectDetailProps::load(params.slug).await?;
+    Ok(Html(ProjectDetailPage::render(&props).to_string()))
+}
+
+fn render_readme(readme: &Readme) -> String {
+    let html = render_untrusted_markdown(&readme.markdown);
+    let html = rewrite_attribute(&html, "href", |target| {
+        resolve(&readme.html_url, &readme.path, target)
+    });
+    rewrite_attribute(&html, "src", |target| {
+        resolve(&readme.download_url, &readme.path, target)
+    })
+}
+
+/// Resolves a link in the README against `readme_url`. Leading slashes are
+/// relative to the repository root rather than the host; anchors and absolute
+/// URLs are left alone.
+fn resolve(readme_url: &str, readme_path: &str, target: &str) -> Option<String> {
+    if target.is_empty() || target.starts_with('#') || url::Url::parse(target).is_ok() {
+        return None;
+    }
+    let resolved = match target.strip_prefix('/') {
+        Some(rooted) => url::Url::parse(readme_url.strip_suffix(readme_path)?)
+            .ok()?
+            .join(rooted),
+        None => url::Url::parse(readme_url).ok()?.join(target),
+    };
+    resolved.ok().map(String::from)
+}
+
+/// Rewrites every quoted `attribute="..."` value in `html` that `rewrite`
+/// returns a replacement for.
+fn rewrite_attribute(
+    html: &str,
+    attribute: &str,
+    rewrite: impl Fn(&str) -> Option<String>,
+) -> String {
+    let needle = format!("{attribute}=");
+    let mut rewritten = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(index) = rest.find(&needle) {
+        let value_start = index + needle.len();
+        let is_attribute = rest[..index]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        rewritten.push_str(&rest[..value_start]);
+        rest = &rest[value_start..];
+
+        let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+            continue;
+        };
+        let Some(length) = rest[1..].find(quote) else {
+            continue;
+        };
+        if !is_attribute {
+            continue;
+        }
+        let value = &rest[1..1 + length];
+        rewritten.push(quote);
+        rewritten.push_str(&rewrite(value).unwrap_or_else(|| value.to_string()));
+        rewritten.push(quote);
+        rest = &rest[length + 2..];
+    }
+    rewritten.push_str(rest);
+    rewritten
+}
+
+/// Whether the essay's title, brief or tags name the project as a whole word.
+/// Only the post index is read, so a project page costs one request for
+/// essays however many there are.
+fn mentions(post: &Post, name: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_');
+    let tags = post.tags.iter().map(|tag| tag.name.as_str());
+    let text = [post.title.as_str(), post.brief.as_str()]
+        .into_iter()
+        .chain(tags)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .to_lowercase();
+    let name = name.to_lowercase();
+    text.match_indices(&name).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + name.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+fn plural(count: u32, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+#[component]
+pub fn ProjectDetailPage(
+    ProjectDetailProps {
+        project,
+        readme_html,
+        essays,
+    }: &ProjectDetailProps,
+) -> Node {
+    let path = format!("/projects/{}", project.name);
+    let source = SoftwareSourceCode::new(project, Person::from(&load_resume()));
+    let breadcrumbs = BreadcrumbList::new(&[("Projects", "/projects"), (&project.name, &path)]);
+    let description = (!project.description.is_empty()).then(|| project.description.clone());
+    let source_url = http_url(&project.url);
+    let homepage = http_url(&project.homepage);
+    let screenshots: Vec<String> = project
+        .screenshots
+        .iter()
+        .filter_map(|screenshot| http_url(screenshot))
+        .collect();
+    let screenshot_alt = escape_xml(&format!("{} screenshot", project.name));
+    let (source_icon, source_label) = match project.url.starts_with("https://github.com/") {
+        true => ("fab fa-github mr-2", "View on GitHub"),
+        false => ("fas fa-code-branch mr-2", "View source"),
+    };
+
+    rsx! {
+        <PageLayout
+            title={&project.name}
+            og_image={Some(og_image_url("projects", &project.name))}
+            description={description}
+            canonical={Some(path.clone())}
+            meta={Vec::new()}
+        >
+            <article class="max-w-3xl mx-auto py-6 md:py-10">
+                <JsonLd data={vec![source.into(), breadcrumbs.into()]} />
+                <header class="mb-10 space-y-4">
+                    <a href="/projects" class="inline-flex items-center gap-2 text-sm text-zinc-500 hover:text-white transition-colors mb-2">
+                        <i class="fas fa-arrow-left text-xs"></i>
+                        <span>"All Projects"</span>
+                    </a>
+                    <div class="flex items-center flex-wrap gap-3 text-xs text-zinc-500">
+                        {when!(let Some(language) = &project.language =>
+                            <span class="inline-flex items-center px-2.5 py-1 text-[11px] uppercase tracking-[0.08em] font-semibold rounded-md" style="background: var(--accent-dim); border: 1px solid var(--accent-border); color: var(--accent);">
+                                {language}
+                            </span>
+                        )}
+                        <span title="Stars"><i class="fas fa-star mr-1"></i>{project.stargazers_count}</span>
+                        {when!(let Some(releases) = project.releases =>
+                            <span title="Releases"><i class="fas fa-tag mr-1"></i>{plural(releases, "release")}</span>
+                        )}
+                        <span title="Open issues"><i class="fas fa-circle-dot mr-1"></i>{plural(project.open_issues, "open issue")}</span>
+                        {when!(let Some(license) = &project.license =>
+                            <span title="License"><i class="fas fa-scale-balanced mr-1"></i>{license}</span>
+                        )}
+                    </div>
+                    <h1 class="text-3xl md:text-5xl font-bold text-white leading-tight">
+                        {&project.name}
+                    </h1>
+                    {when!(!project.description.is_empty() =>
+                        <p class="text-lg text-zinc-400 leading-relaxed">{&project.description}</p>
+                    )}
+                    {if !project.tags.is_empty() {
+                        rsx! {
+                            <div class="flex flex-wrap gap-1.5">
+                                {project.tags.iter().map(|tag| rsx! {
+                                    <span class="text-xs px-2 py-0.5 rounded-full border border-zinc-800 text-zinc-500">{tag.replace('-', " ")}</span>
+                                })}
+                            </div>
+                        }
+                    } else {
+                        rsx! { <></> }
+                    }}
+                    <div class="flex gap-3 flex-wrap">
+                        {when!(let Some(source_url) = &source_url =>
+                            <a href={source_url.as_str()} class="btn-accent px-5 py-2 text-sm font-medium rounded-lg">
+                                <i class={source_icon}></i>{source_label}
+                            </a>
+                        )}
+                        {when!(let Some(homepage) = &homepage =>
+                            <a href={homepage.as_str()} class="btn-ghost px-5 py-2 text-sm font-medium rounded-lg">
+                                <i class="fas fa-arrow-up-right-from-square mr-2"></i>"Homepage"
+                            </a>
+                        )}
+                    </div>
+                    <div class="section-rule"></div>
+                </header>
+
+                {if !screenshots.is_empty() {
+                    rsx! {
+                        <section class="mb-10 grid grid-cols-1 sm:grid-cols-2 gap-4" aria_label="Screenshots">
+                            {screenshots.iter().map(|screenshot| rsx! {
+                                <a href={screenshot.as_str()} target="_blank" rel="noopener noreferrer">
+                                    <img
+                                        src={screenshot.as_str()}
+                                        alt={screenshot_alt.as_str()}
+                                        loading="lazy"
+                                        class="w-full rounded-lg border border-zinc-800"
+                                    />
+                                </a>
+                            })}
+                        </section>
+                    }
+                } else {
+                    rsx! { <></> }
+                }}
+
+                {match readme_html {
+                    Some(html) => rsx! {
+                        <div class="essay-body">
+                            <div _dangerously_set_inner_html={html.clone()} />
+                        </div>
+                    },
+                    None => rsx! {
+                        <p class="text-sm text-zinc-500">"This project has no README yet."</p>
+                    },
+                }}
+
+                {if !essays.is_empty() {
+                    rsx! {
+                        <footer class="mt-16 pt-8 border-t border-zinc-800 space-y-6">
+                            <h2 class="text-xl font-semibold text-white">"Essays mentioning this project"</h2>
+                            <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                                {essays.iter().map(|post| rsx! {
+                                    <Article post={post.clone()} show_read_more={true} />
+                                })}
+                            </div>
+                        </footer>
+                    }
+                } else {
+                    rsx! { <></> }
+                }}
+            </article>
+        </PageLayout>
+    }
+}
@@ -0,0 +1,421 @@
+use crate::app::error::AppError;
+use crate::cache::TtlCache;
+use crate::components::PageLayout;
+use crate::components::chart::{ACCENT, Bar, BarChart, DonutChart, NEUTRAL};
+use crate::requests::{Manifests, get_every_project, get_project_manifests};
+use crate::shared::Project;
+use axum::response::{Html, IntoResponse};
+use momenta::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+const STATS_TTL: i64 = 6 * 3600;
+const MAX_PROJECT_PAGES: u32 = 20;
+const TOP_LANGUAGES: usize = 10;
+const TOP_DEPENDENCIES: usize = 12;
+
+lazy_static::lazy_static! {
+    static ref STATS_CACHE: TtlCache<(), ProjectStatsProps> = TtlCache::new(STATS_TTL);
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LanguageTotal {
+    pub name: String,
+    pub color: Option<String>,
+    pub bytes: u64,
+    /// Repositories using the language at all.
+    pub repos: u32,
+    pub percent: f32,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DependencyCount {
+    pub name: String,
+    pub repos: u32,
+}
+
+/// Dependencies declared in one kind of manifest across all repositories.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Ecosystem {
+    pub name: String,
+    pub manifest: String,
+    /// Repositories with this manifest at their root.
+    pub repos: u32,
+    pub dependencies: Vec<DependencyCount>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct ProjectStatsProps {
+    pub repos: usize,
+    /// Whether `percent` is by bytes of code; without a language breakdown
+    /// it falls back to the share of repositories.
+    pub by_bytes: bool,
+    pub languages: Vec<LanguageTotal>,
+    pub ecosystems: Vec<Ecosystem>,
+}
+
+impl ProjectStatsProps {
+    async fn load() -> Result<Self, AppError> {
+        STATS_CACHE.get_or_refresh((), Self::aggregate()).await
+    }
+
+    async fn aggregate() -> Result<Self, AppError> {
+        let projects = get_every_project(MAX_PROJECT_PAGES).await?;
+        let manifests = get_project_manifests(&projects).await?;
+        let (languages, by_bytes) = language_totals(&projects);
+
+        let manifests: Vec<&Manifests> = manifests.values().collect();
+        let ecosystems = [
+            tally(
+                "Rust crates",
+                "Cargo.toml",
+                manifests.iter().filter_map(|m| m.cargo_toml.as_deref()),
+                cargo_dependencies,
+            ),
+            tally(
+                "npm packages",
+                "package.json",
+                manifests.iter().filter_map(|m| m.package_json.as_deref()),
+                npm_dependencies,
+            ),
+            tally(
+                "Python packages",
+                "pyproject.toml",
+                manifests.iter().filter_map(|m| m.pyproject_toml.as_deref()),
+                python_dependencies,
+            ),
+        ]
+        .into_iter()
+        .filter(|ecosystem| ecosystem.repos > 0)
+        .collect();
+
+        Ok(Self {
+            repos: projects.len(),
+            by_bytes,
+            languages,
+            ecosystems,
+        })
+    }
+}
+
+/// Sums language bytes across projects, keeping the largest languages and
+/// folding the rest into "Other".
+fn language_totals(projects: &[Project]) -> (Vec<LanguageTotal>, bool) {
+    let mut totals: Vec<LanguageTotal> = Vec::new();
+    let mut add = |name: &str, color: Option<&String>, bytes: u64| match totals
+        .iter_mut()
+        .find(|total| total.name == name)
+    {
+        Some(total) => {
+            total.bytes += bytes;
+            total.repos += 1;
+        }
+        None => totals.push(LanguageTotal {
+            name: name.to_string(),
+            color: color.cloned(),
+            bytes,
+            repos: 1,
+            percent: 0.0,
+        }),
+    };
+    for project in projects {
+        match (&project.language, project.languages.is_empty()) {
+            (_, false) => project
+                .languages
+                .iter()
+                .for_each(|language| add(&language.name, language.color.as_ref(), language.bytes)),
+            (Some(language), true) => add(language, None, 0),
+            (None, true) => {}
+        }
+    }
+
+    let total_bytes: u64 = totals.iter().map(|total| total.bytes).sum();
+    let total_repos: u32 = totals.iter().map(|total| total.repos).sum();
+    let by_bytes = total_bytes > 0;
+    totals.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| b.repos.cmp(&a.repos)));
+    if totals.len() > TOP_LANGUAGES {
+        let rest = totals.split_off(TOP_LANGUAGES - 1);
+        totals.push(LanguageTotal {
+            name: "Other".to_string(),
+            color: None,
+            bytes: rest.iter().map(|total| total.bytes).sum(),
+            repos: rest.iter().map(|total| total.repos).sum(),
+            percent: 0.0,
+        });
+    }
+    for total in &mut totals {
+        total.percent = match by_bytes {
+            true => 100.0 * total.bytes as f32 / total_bytes as f32,
+            false => 100.0 * total.repos as f32 / total_repos.max(1) as f32,
+        };
+    }
+    (totals, by_bytes)
+}
+
+/// Counts how many manifests declare each dependency.
+fn tally<'a>(
+    name: &str,
+    manifest: &str,
+    sources: impl Iterator<Item = &'a str>,
+    parse: fn(&str) -> Vec<String>,
+) -> Ecosystem {
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    let mut repos = 0;
+    for source in sources {
+        repos += 1;
+        // A dependency listed in several sections still counts once per repo.
+        for dependency in parse(source).into_iter().collect::<BTreeSet<_>>() {
+            *counts.entry(dependency).or_default() += 1;
+        }
+    }
+    let mut dependencies: Vec<DependencyCount> = counts
+        .into_iter()
+        .map(|(name, repos)| DependencyCount { name, repos })
+        .collect();
+    dependencies.sort_by(|a, b| b.repos.cmp(&a.repos).then_with(|| a.name.cmp(&b.name)));
+    dependencies.truncate(TOP_DEPENDENCIES);
+    Ecosystem {
+        name: name.to_string(),
+        manifest: manifest.to_string(),
+        repos,
+        dependencies,
+    }
+}
+
+fn cargo_dependencies(manifest: &str) -> Vec<String> {
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    fn table(value: Option<&toml::Value>) -> Option<&toml::Table> {
+        value.and_then(toml::Value::as_table)
+    }
+    let sections = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+    let mut tables: Vec<&toml::Table> = sections
+        .iter()
+        .filter_map(|section| table(manifest.get(*section)))
+        .collect();
+    tables.extend(table(
+        manifest
+            .get("workspace")
+            .and_then(|w| w.get("dependencies")),
+    ));
+    for target in table(manifest.get("target"))
+        .into_iter()
+        .flat_map(|t| t.values())
+    {
+        tables.extend(
+            sections
+                .iter()
+                .filter_map(|section| table(target.get(*section))),
+        );
+    }
+
+    tables
+        .into_iter()
+        .flat_map(|dependencies| dependencies.iter())
+        // Path-only entries are the repository's own crates.
+        .filter(|(_, spec)| spec.get("path").is_none() || spec.get("version").is_some())
+        .map(|(name, spec)| {
+            spec.get("package")
+                .and_then(toml::Value::as_str)
+                .unwrap_or(name)
+                .to_string()
+        })
+        .collect()
+}
+
+fn npm_dependencies(manifest: &str) -> Vec<String> {
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(manifest) else {
+        return Vec::new();
+    };
+    ["dependencies", "devDependencies", "peerDependencies"]
+        .iter()
+        .filter_map(|section| manifest.get(*section)?.as_object())
+        .flat_map(|dependencies| dependencies.iter())
+        // Workspace and file links are the repository's own packages.
+        .filter(|(_, version)| {
+            !version.as_str().is_some_and(|version| {
+                version.starts_with("workspace:") || version.starts_with("file:")
+            })
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Reads PEP 621 `[project]` requirements and Poetry dependency tables.
+fn python_dependencies(manifest: &str) -> Vec<String> {
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+
+    if let Some(project) = manifest.get("project") {
+        let optional = project
+            .get("optional-dependencies")
+            .and_then(toml::Value::as_table)
+            .into_iter()
+            .flat_map(|groups| groups.values());
+        let requirements = project
+            .get("dependencies")
+            .into_iter()
+            .chain(optional)
+            .filter_map(toml::Value::as_array)
+            .flatten()
+            .filter_map(toml::Value::as_str);
+        names.extend(requirements.filter_map(requirement_name));
+    }
+
+    if let Some(poetry) = manifest.get("tool").and_then(|tool| tool.get("poetry")) {
+        let groups = poetry
+            .get("group")
+            .and_then(toml::Value::as_table)
+            .into_iter()
+            .flat_map(|groups| groups.values())
+            .filter_map(|group| group.get("dependencies"));
+        let tables = poetry
+            .get("dependencies")
+            .into_iter()
+            .chain(groups)
+            .filter_map(toml::Value::as_table);
+        names.extend(
+            tables
+                .flat_map(|dependencies| dependencies.keys())
+                .filter(|name| *name != "python")
+                .filter_map(|name| requirement_name(name)),
+        );
+    }
+    names
+}
+
+/// The normalised package name at the start of a PEP 508 requirement such as
+/// `requests[socks]>=2.31; python_version > "3.8"`.
+fn requirement_name(requirement: &str) -> Option<String> {
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    (!name.is_empty()).then(|| name.to_lowercase().replace(['_', '.'], "-"))
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1_000 => format!("{bytes} B"),
+        1_000..1_000_000 => format!("{:.1} KB", bytes as f32 / 1e3),
+        _ => format!("{:.1} MB", bytes as f32 / 1e6),
+    }
+}
+
+fn repositories(count: u32) -> String {
+    match count {
+        1 => "1 repository".to_string(),
+        _ => format!("{count} repositories"),
+    }
+}
+
+pub async fn project_stats_handler() -> Result<impl IntoResponse, AppError> {
+    let props = ProjectStatsProps::load().await?;
+    Ok(Html(ProjectStatsPage::render(&props).to_string()))
+}
+
+#[component]
+pub fn ProjectStatsPage(
+    ProjectStatsProps {
+        repos,
+        by_bytes,
+        languages,
+        ecosystems,
+    }: &ProjectStatsProps,
+) -> Node {
+    let color = |language: &LanguageTotal| language.color.clone().unwrap_or(NEUTRAL.to_string());
+    let language_bars: Vec<Bar> = languages
+        .iter()
+        .map(|language| Bar {
+            label: language.name.clone(),
+            value: language.percent,
+            display: format!("{:.1}%", language.percent),
+            color: color(language),
+            tooltip: match by_bytes {
+                true => format!(
+                    "{}: {} across {}",
+                    language.name,
+                    format_bytes(language.bytes),
+                    repositories(language.repos)
+                ),
+                false => format!("{}: {}", language.name, repositories(language.repos)),
+            },
+        })
+        .collect();
+    let segments: Vec<(String, f32, String)> = languages
+        .iter()
+        .map(|language| (language.name.clone(), language.percent, color(language)))
+        .collect();
+    let measure = if *by_bytes {
+        "Share of code by bytes, as measured by GitHub's linguist."
+    } else {
+        "Share of projects by primary language."
+    };
+    let description = format!(
+        "Languages and dependencies across {repos} public repositories, aggregated from GitHub."
+    );
+
+    rsx! {
+        <PageLayout
+            title="Project Stats"
+            og_image={None}
+            description={Some(description.clone())}
+            canonical={Some("/projects/stats".to_string())}
+            meta={Vec::new()}
+        >
+            <div class="py-4 md:py-8 space-y-10">
+                <section class="space-y-4">
+                    <a href="/projects" class="inline-flex items-center gap-2 text-sm text-zinc-500 hover:text-white transition-colors">
+                        <i class="fas fa-arrow-left text-xs"></i>
+                        <span>"All Projects"</span>
+                    </a>
+                    <h1 class="text-4xl md:text-5xl font-semibold text-white">"Stack"</h1>
+                    <div class="section-rule"></div>
+                    <p class="text-base text-zinc-300 max-w-3xl">{description}</p>
+                </section>
+
+                <section class="space-y-4">
+                    <h2 class="text-2xl font-semibold text-white">"Languages"</h2>
+                    <p class="text-sm text-zinc-500">{measure}</p>
+                    <div class="grid grid-cols-1 md:grid-cols-[12rem_1fr] gap-8 items-center card-item rounded-lg p-5">
+                        <div class="max-w-[12rem] mx-auto w-full">
+                            <DonutChart title="Language share" segments={segments} />
+                        </div>
+                        <BarChart title="Language share" bars={language_bars} />
+                    </div>
+                </section>
+
+                {ecosystems.iter().map(|ecosystem| {
+                    let bars: Vec<Bar> = ecosystem
+                        .dependencies
+                        .iter()
+                        .map(|dependency| Bar {
+                            label: dependency.name.clone(),
+                            value: dependency.repos as f32,
+                            display: format!("{}/{}", dependency.repos, ecosystem.repos),
+                            color: ACCENT.to_string(),
+                            tooltip: format!(
+                                "{} is declared in {} of {} {} files",
+                                dependency.name, dependency.repos, ecosystem.repos, ecosystem.manifest
+                            ),
+                        })
+                        .collect();
+                    <section class="space-y-4">
+                        <h2 class="text-2xl font-semibold text-white">{&ecosystem.name}</h2>
+                        <p class="text-sm text-zinc-500">
+                            {format!("Most used dependencies across {} with a {}.", repositories(ecosystem.repos), ecosystem.manifest)}
+                        </p>
+                        <div class="card-item rounded-lg p-5">
+                            <BarChart title={format!("Most used {}", ecosystem.name)} bars={bars} />
+                        </div>
+                    </section>
+                })}
+            </div>
+        </PageLayout>
+    }
+}
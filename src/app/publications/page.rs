@@ -0,0 +1,505 @@
+use crate::app::error::AppError;
+use crate::citation::{self, CitationFormat};
+use crate::components::PageLayout;
+use crate::components::filter::{FilterNav, FilterNavProps, Filters};
+use crate::components::structured_data::{JsonLd, Periodical, ScholarlyArticle};
+use crate::datastar::{Event, ExecuteScript, MergeFragments, MergeMode, Sse};
+use crate::publications::{
+    PublicationEntry, PublicationFacets, fetch_publications, normalize_orcid_id,
+};
+use crate::shared::escape_xml;
+use crate::shared::{PageQuery, PublicationGrouping, PublicationQuery, get_env};
+use axum::{
+    extract::{Path, Query},
+    http::header,
+    response::{Html, IntoResponse},
+};
+use momenta::prelude::*;
+use serde::{Deserialize, Serialize};
+
+impl From<&PublicationEntry> for ScholarlyArticle {
+    fn from(entry: &PublicationEntry) -> Self {
+        ScholarlyArticle {
+            headline: entry.title.clone(),
+            name: entry.title.clone(),
+            url: entry.url.clone(),
+            date_published: entry.published_on.clone(),
+            genre: entry.work_type.clone(),
+            is_part_of: entry.venue.clone().map(|name| Periodical { name }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PublicationsProps {
+    pub orcid_id: Option<String>,
+    pub orcid_url: Option<String>,
+    /// The works matching `query`, newest first.
+    pub publications: Vec<PublicationEntry>,
+    /// Every work, before filtering.
+    pub total: usize,
+    pub facets: PublicationFacets,
+    pub query: PublicationQuery,
+    pub notice: Option<String>,
+}
+
+impl PublicationsProps {
+    async fn load(query: PublicationQuery) -> Result<Self, AppError> {
+        let (orcid_id, publications) = load_publications().await?;
+        let facets = PublicationFacets::new(&publications);
+        let total = publications.len();
+        let publications = publications
+            .into_iter()
+            .filter(|entry| query.matches(entry))
+            .collect();
+        if orcid_id.is_empty() {
+            return Ok(Self {
+                publications,
+                total,
+                facets,
+                query,
+                notice: Some(
+                    "Set your publication profile ID in your environment to sync automatically."
+                        .to_string(),
+                ),
+                ..Default::default()
+            });
+        }
+
+        let orcid_url = format!("https://orcid.org/{orcid_id}");
+        Ok(Self {
+            orcid_id: Some(orcid_id),
+            orcid_url: Some(orcid_url),
+            publications,
+            total,
+            facets,
+            query,
+            notice: None,
+        })
+    }
+}
+
+/// The configured ORCID iD and every work, newest first.
+async fn load_publications() -> Result<(String, Vec<PublicationEntry>), AppError> {
+    let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+    let publications = fetch_publications(&orcid_id).await?;
+    Ok((orcid_id, publications))
+}
+
+pub async fn publications_handler(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let props = PublicationsProps::load(PublicationQuery::from_params(&query)).await?;
+    Ok(Html(PublicationsPage::render(&props).to_string()))
+}
+
+/// The listing for the filters in the request's signals, replacing the one
+/// on the page, with the address bar updated to match.
+pub async fn filter_handler(
+    Query(query): Query<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    let filters = PublicationQuery::from_params(&PageQuery::signals(&query));
+    let (_, publications) = load_publications().await?;
+    let publications = publications
+        .into_iter()
+        .filter(|entry| filters.matches(entry))
+        .collect();
+
+    Ok(Sse::from_iter([
+        Event::from(
+            MergeFragments::new(PublicationList::render(&PublicationListProps {
+                publications,
+                grouping: filters.grouping,
+            }))
+            .selector("#publication_list")
+            .mode(MergeMode::Outer),
+        ),
+        ExecuteScript::new(format!(
+            "history.replaceState(null, '', {})",
+            serde_json::Value::from(filters.href())
+        ))
+        .into(),
+    ]))
+}
+
+/// A `PublicationQuery` under the names its URL parameters and signals use.
+fn signals(query: &PublicationQuery) -> serde_json::Value {
+    serde_json::json!({
+        "type": query.work_type.as_deref().unwrap_or_default(),
+        "venue": query.venue.as_deref().unwrap_or_default(),
+        "year": query.year.as_deref().unwrap_or_default(),
+        "keyword": query.keyword.as_deref().unwrap_or_default(),
+        "q": query.search.as_deref().unwrap_or_default(),
+        "by": query.grouping.as_str(),
+        // Filter links reset it, as on the paged listings.
+        "cursor": "",
+    })
+}
+
+#[derive(Deserialize)]
+pub struct ExportParams {
+    pub format: String,
+}
+
+#[derive(Deserialize)]
+pub struct WorkExportParams {
+    pub slug: String,
+    pub format: String,
+}
+
+/// Every publication in one BibTeX, RIS or CSL-JSON file.
+pub async fn export_handler(
+    Path(ExportParams { format }): Path<ExportParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = export_format(&format)?;
+    let (_, publications) = load_publications().await?;
+    Ok(export_response(format, "publications", &publications))
+}
+
+/// A single publication, looked up by [`PublicationEntry::id`].
+pub async fn work_export_handler(
+    Path(WorkExportParams { slug, format }): Path<WorkExportParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let format = export_format(&format)?;
+    let (_, publications) = load_publications().await?;
+    let entry = publications
+        .into_iter()
+        .find(|entry| entry.id == slug)
+        .ok_or(AppError::NotFound)?;
+    Ok(export_response(format, &slug, &[entry]))
+}
+
+fn export_format(format: &str) -> Result<CitationFormat, AppError> {
+    CitationFormat::parse(format).ok_or(AppError::NotFound)
+}
+
+fn export_response(
+    format: CitationFormat,
+    name: &str,
+    entries: &[PublicationEntry],
+) -> impl IntoResponse + use<> {
+    let disposition = format!("inline; filename=\"{name}.{}\"", format.extension());
+    (
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::CACHE_CONTROL, "public, max-age=3600".to_string()),
+        ],
+        format.export(entries),
+    )
+}
+
+#[component]
+pub fn PublicationsPage(
+    PublicationsProps {
+        publications,
+        total,
+        facets,
+        query,
+        notice,
+        ..
+    }: &PublicationsProps,
+) -> Node {
+    let sync_status = if *total > 0 {
+        format!("({total} synced)")
+    } else {
+        String::from("(ready to sync)")
+    };
+    let notice_text = notice
+        .as_deref()
+        .unwrap_or("Add your publication profile ID and the page will populate automatically.");
+    let filters = Filters {
+        signals: signals(query),
+        endpoint: "/publications/filter",
+        href: |params| PublicationQuery::from_params(params).href(),
+    };
+    let facet_pills = |signal: &str, every: &str, values: &[(String, usize)], limit: usize| {
+        let mut pills = vec![filters.pill(signal, "", every.to_string())];
+        pills.extend(
+            values
+                .iter()
+                .take(limit)
+                .map(|(value, count)| filters.pill(signal, value, format!("{value} ({count})"))),
+        );
+        pills
+    };
+    let navs = [
+        FilterNavProps {
+            label: "Group publications",
+            pills: PublicationGrouping::ALL
+                .iter()
+                .map(|grouping| filters.pill("by", grouping.as_str(), grouping.label().to_string()))
+                .collect(),
+        },
+        FilterNavProps {
+            label: "Filter by type",
+            pills: facet_pills("type", "Every type", &facets.work_types, usize::MAX),
+        },
+        FilterNavProps {
+            label: "Filter by year",
+            pills: facet_pills("year", "Any year", &facets.years, usize::MAX),
+        },
+        FilterNavProps {
+            label: "Filter by venue",
+            pills: facet_pills("venue", "Any venue", &facets.venues, 12),
+        },
+    ];
+    let focus_areas = FilterNavProps {
+        label: "Focus areas",
+        pills: facet_pills("keyword", "All", &facets.keywords, 12),
+    };
+    let search = escape_xml(query.search.as_deref().unwrap_or_default());
+    // Filters other than the search, so submitting it without scripts keeps them.
+    let kept: Vec<(&str, String)> = [
+        ("type", query.work_type.clone()),
+        ("venue", query.venue.clone()),
+        ("year", query.year.clone()),
+        ("keyword", query.keyword.clone()),
+        (
+            "by",
+            (query.grouping != PublicationGrouping::default())
+                .then(|| query.grouping.as_str().to_string()),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| Some((name, escape_xml(&value?))))
+    .collect();
+    let structured_data: Vec<_> = publications
+        .iter()
+        .map(|entry| ScholarlyArticle::from(entry).into())
+        .collect();
+
+    rsx! {
+        <PageLayout title="Publications" og_image={None} description={None} canonical={Some("/publications".to_string())} meta={Vec::new()}>
+            <JsonLd data={structured_data} />
+            <section class="py-4 md:py-8 space-y-8" data_signals={filters.data_signals()}>
+                <div class="space-y-4 max-w-4xl">
+                    <div class="flex items-center gap-3 flex-wrap">
+                        <h1 class="text-4xl md:text-5xl font-semibold text-white">"Publications"</h1>
+                    </div>
+                    <div class="section-rule"></div>
+                    <p class="text-base md:text-lg text-zinc-300 leading-relaxed max-w-3xl">
+                        "Selected writing and research notes across biodiagnostics, AI, and microfluidics. "
+                        <span class="text-sm" style="color: var(--accent); opacity: 0.7;">{sync_status.as_str()}</span>
+                    </p>
+                    {when!(*total > 0 =>
+                        <p class="text-sm text-zinc-500">
+                            "Export all as "
+                            {CitationFormat::ALL.iter().enumerate().map(|(index, format)| {
+                                let href = format!("/publications/export/{}", format.as_str());
+                                <>
+                                    {when!(index > 0 => <>", "</>)}
+                                    <a href={href} class="nav-link">{format.label()}</a>
+                                </>
+                            })}
+                        </p>
+                    )}
+                </div>
+
+                <div class="grid grid-cols-1 lg:grid-cols-[1fr_320px] gap-6 items-start">
+                    <div class="space-y-6">
+                        {if *total == 0 {
+                            rsx! {
+                                <div class="card-item rounded-2xl p-6 space-y-3">
+                                    <p class="text-sm text-zinc-300">"No publications are synced yet."</p>
+                                    <p class="text-sm text-zinc-500 leading-relaxed">{notice_text}</p>
+                                </div>
+                            }
+                        } else {
+                            rsx! {
+                                <div class="space-y-6">
+                                    <div class="space-y-4">
+                                        {FilterNav::render(&navs[0])}
+                                        <form action="/publications" method="get" role="search" class="relative" data_on_submit={filters.on_submit()}>
+                                            {kept.iter().map(|(name, value)| {
+                                                <input type="hidden" name={*name} value={value.as_str()} />
+                                            })}
+                                            <i class="fas fa-search absolute left-4 top-1/2 -translate-y-1/2 text-zinc-500 text-sm pointer-events-none"></i>
+                                            <input
+                                                id="search-input"
+                                                type="search"
+                                                name="q"
+                                                value={search.as_str()}
+                                                data_bind="q"
+                                                placeholder="Search publications..."
+                                                class="w-full bg-zinc-900/60 border border-zinc-800 rounded-xl pl-10 pr-4 py-3 text-sm text-zinc-200 placeholder-zinc-600 focus:outline-none focus:border-zinc-700 transition-colors"
+                                            />
+                                        </form>
+                                        {navs[1..].iter().map(FilterNav::render)}
+                                    </div>
+                                    <PublicationList publications={publications.clone()} grouping={query.grouping} />
+                                </div>
+                            }
+                        }}
+                    </div>
+
+                    <aside class="space-y-4">
+                        {when!(!facets.keywords.is_empty() =>
+                            <div class="card-item rounded-2xl p-5 space-y-3">
+                                <p class="text-xs uppercase tracking-[0.1em]" style="color: var(--accent);">"Focus areas"</p>
+                                <FilterNav {..focus_areas} />
+                            </div>
+                        )}
+                    </aside>
+                </div>
+            </section>
+        </PageLayout>
+    }
+}
+
+/// The works in sections, by year newest first or by type most common
+/// first, keeping each section newest first.
+fn sections(
+    publications: &[PublicationEntry],
+    grouping: PublicationGrouping,
+) -> Vec<(String, Vec<PublicationEntry>)> {
+    let mut sections: Vec<(String, Vec<PublicationEntry>)> = Vec::new();
+    for entry in publications {
+        let heading = match grouping {
+            PublicationGrouping::Year => entry.year().unwrap_or("Undated"),
+            PublicationGrouping::Type => entry.work_type.as_deref().unwrap_or("Other"),
+        };
+        match sections.iter_mut().find(|(seen, _)| seen == heading) {
+            Some((_, entries)) => entries.push(entry.clone()),
+            None => sections.push((heading.to_string(), vec![entry.clone()])),
+        }
+    }
+    match grouping {
+        PublicationGrouping::Year => sections.sort_by(|a, b| match (a.0.as_str(), b.0.as_str()) {
+            ("Undated", _) => std::cmp::Ordering::Greater,
+            (_, "Undated") => std::cmp::Ordering::Less,
+            (a, b) => b.cmp(a),
+        }),
+        PublicationGrouping::Type => {
+            sections.sort_by_key(|(_, entries)| std::cmp::Reverse(entries.len()))
+        }
+    }
+    sections
+}
+
+pub struct PublicationListProps {
+    pub publications: Vec<PublicationEntry>,
+    pub grouping: PublicationGrouping,
+}
+
+#[component]
+fn PublicationList(
+    PublicationListProps {
+        publications,
+        grouping,
+    }: &PublicationListProps,
+) -> Node {
+    let sections = sections(publications, *grouping);
+
+    rsx! {
+        <div id="publication_list" class="space-y-10">
+            {when!(sections.is_empty() =>
+                <p class="text-sm text-zinc-500">"No publications match these filters."</p>
+            )}
+            {sections.iter().map(|(heading, entries)| {
+                <section class="space-y-4">
+                    <h2 class="text-sm uppercase tracking-[0.1em] text-zinc-500">
+                        {heading}
+                        <span class="ml-2 text-zinc-600">{entries.len().to_string()}</span>
+                    </h2>
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                        {entries.iter().map(|entry| {
+                            <PublicationCard entry={entry.clone()} />
+                        })}
+                    </div>
+                </section>
+            })}
+        </div>
+    }
+}
+
+pub struct PublicationCardProps {
+    pub entry: PublicationEntry,
+}
+
+#[component]
+fn PublicationCard(PublicationCardProps { entry }: &PublicationCardProps) -> Node {
+    let authors = author_line(&entry.authors);
+    let citations = entry
+        .citations
+        .filter(|count| *count > 0)
+        .map(|count| match count {
+            1 => "Cited once".to_string(),
+            count => format!("Cited {count} times"),
+        });
+    let href = escape_xml(&format!("/publications/{}", entry.id));
+    let url = entry.url.as_deref().map(escape_xml);
+
+    rsx! {
+        <article class="card-item rounded-2xl p-5 space-y-4 soft-lift">
+            <div class="flex items-start justify-between gap-3">
+                <div class="space-y-2">
+                    <p class="text-xs uppercase tracking-[0.1em] text-zinc-500">{entry.work_type.as_deref().unwrap_or("Publication")}</p>
+                    <h3 class="text-xl font-semibold text-zinc-100 leading-snug">
+                        <a href={href} class="hover:text-white transition-colors">{&entry.title}</a>
+                    </h3>
+                </div>
+                {when!(let Some(url) = &url =>
+                    <a href={url.as_str()} target="_blank" rel="noopener noreferrer" class="social-link shrink-0" aria_label="Open publication">
+                        <i class="fas fa-arrow-up-right-from-square text-sm"></i>
+                    </a>
+                )}
+            </div>
+            <div class="space-y-1 text-sm text-zinc-400">
+                {when!(let Some(venue) = &entry.venue => <p>{venue}</p>)}
+                {when!(let Some(published_on) = &entry.published_on => <p>{published_on}</p>)}
+                {when!(let Some(authors) = &authors => <p class="text-zinc-500">{authors}</p>)}
+                {when!(let Some(citations) = &citations => <p style="color: var(--accent);">{citations}</p>)}
+            </div>
+            <CiteActions entry={entry.clone()} />
+        </article>
+    }
+}
+
+/// A "Cite" disclosure with the work formatted in each citation style and
+/// links to download it for a reference manager.
+pub struct CiteActionsProps {
+    pub entry: PublicationEntry,
+}
+
+#[component]
+pub fn CiteActions(CiteActionsProps { entry }: &CiteActionsProps) -> Node {
+    let styles = [
+        ("APA", citation::apa(entry)),
+        ("MLA", citation::mla(entry)),
+        ("Chicago", citation::chicago(entry)),
+    ];
+    let id = escape_xml(&entry.id);
+
+    rsx! {
+        <details class="text-sm text-zinc-400 border-t border-zinc-800 pt-3">
+            <summary class="cursor-pointer text-xs uppercase tracking-[0.1em] text-zinc-500">
+                <i class="fas fa-quote-right mr-2"></i>"Cite"
+            </summary>
+            <div class="space-y-3 pt-3">
+                {styles.iter().map(|(style, text)| {
+                    <div class="space-y-1">
+                        <p class="text-xs uppercase tracking-[0.1em]" style="color: var(--accent);">{*style}</p>
+                        <p class="text-zinc-300 leading-relaxed select-all">{text}</p>
+                    </div>
+                })}
+                <p class="flex flex-wrap gap-3 text-xs">
+                    {CitationFormat::ALL.iter().map(|format| {
+                        let href = format!("/publications/{id}/export/{}", format.as_str());
+                        <a href={href} class="nav-link"><i class="fas fa-download mr-1"></i>{format.label()}</a>
+                    })}
+                </p>
+            </div>
+        </details>
+    }
+}
+
+/// Up to the first few authors, then "et al.".
+fn author_line(authors: &[String]) -> Option<String> {
+    const SHOWN: usize = 4;
+    match authors.len() {
+        0 => None,
+        count if count <= SHOWN => Some(authors.join(", ")),
+        _ => Some(format!("{}, et al.", authors[..SHOWN].join(", "))),
+    }
+}
@@ -0,0 +1,186 @@
+use super::page::CiteActions;
+use crate::app::error::AppError;
+use crate::app::og::og_image_url;
+use crate::components::PageLayout;
+use crate::components::structured_data::{BreadcrumbList, JsonLd, ScholarlyArticle};
+use crate::publications::{PublicationEntry, fetch_publication, normalize_orcid_id};
+use crate::shared::*;
+use axum::{
+    extract::Path,
+    response::{Html, IntoResponse},
+};
+use momenta::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct PublicationDetailProps {
+    pub entry: PublicationEntry,
+}
+
+impl PublicationDetailProps {
+    async fn load(id: String) -> Result<Self, AppError> {
+        let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+        let entry = fetch_publication(&orcid_id, &id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        Ok(Self { entry })
+    }
+}
+
+pub async fn publication_detail_handler(
+    Path(params): Path<PageParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let props = PublicationDetailProps::load(params.slug).await?;
+    Ok(Html(PublicationDetailPage::render(&props).to_string()))
+}
+
+/// Highwire Press tags, which Google Scholar reads to index the work under
+/// this page.
+fn citation_meta(entry: &PublicationEntry) -> Vec<(&'static str, String)> {
+    let mut meta = vec![("citation_title", entry.title.clone())];
+    meta.extend(
+        entry
+            .authors
+            .iter()
+            .map(|author| ("citation_author", author.clone())),
+    );
+    meta.extend(
+        entry
+            .published_on
+            .iter()
+            .map(|date| ("citation_publication_date", date.replace('-', "/"))),
+    );
+    meta.extend(entry.doi.iter().map(|doi| ("citation_doi", doi.clone())));
+    meta.extend(
+        entry
+            .venue
+            .iter()
+            .map(|venue| ("citation_journal_title", venue.clone())),
+    );
+    meta.extend(
+        entry
+            .volume
+            .iter()
+            .map(|volume| ("citation_volume", volume.clone())),
+    );
+    meta.extend(
+        entry
+            .issue
+            .iter()
+            .map(|issue| ("citation_issue", issue.clone())),
+    );
+    if let Some(pages) = &entry.pages {
+        let (first, last) = pages
+            .split_once('-')
+            .map_or((pages.as_str(), None), |(first, last)| (first, Some(last)));
+        meta.push(("citation_firstpage", first.trim().to_string()));
+        meta.extend(last.map(|last| ("citation_lastpage", last.trim().to_string())));
+    }
+    meta
+}
+
+/// `Volume 23, issue 4, pages 45-67`, from whichever parts are known.
+fn issue_line(entry: &PublicationEntry) -> Option<String> {
+    let parts: Vec<String> = [
+        entry
+            .volume
+            .as_ref()
+            .map(|volume| format!("Volume {volume}")),
+        entry.issue.as_ref().map(|issue| format!("issue {issue}")),
+        entry.pages.as_ref().map(|pages| match pages.contains('-') {
+            true => format!("pages {pages}"),
+            false => format!("page {pages}"),
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+#[component]
+pub fn PublicationDetailPage(PublicationDetailProps { entry }: &PublicationDetailProps) -> Node {
+    let path = format!("/publications/{}", entry.id);
+    let article = ScholarlyArticle::from(entry);
+    let breadcrumbs =
+        BreadcrumbList::new(&[("Publications", "/publications"), (&entry.title, &path)]);
+    let issue = issue_line(entry);
+    let citations = entry
+        .citations
+        .filter(|count| *count > 0)
+        .map(|count| match count {
+            1 => "Cited once".to_string(),
+            count => format!("Cited {count} times"),
+        });
+    let url = entry.url.as_deref().map(escape_xml);
+
+    rsx! {
+        <PageLayout
+            title={&entry.title}
+            og_image={Some(og_image_url("publications", &entry.id))}
+            description={entry.summary.clone()}
+            canonical={Some(path.clone())}
+            meta={citation_meta(entry)}
+        >
+            <article class="max-w-3xl mx-auto py-6 md:py-10">
+                <JsonLd data={vec![article.into(), breadcrumbs.into()]} />
+                <header class="mb-10 space-y-4">
+                    <a href="/publications" class="inline-flex items-center gap-2 text-sm text-zinc-500 hover:text-white transition-colors mb-2">
+                        <i class="fas fa-arrow-left text-xs"></i>
+                        <span>"All Publications"</span>
+                    </a>
+                    <div class="flex items-center flex-wrap gap-3 text-xs text-zinc-500">
+                        <span class="inline-flex items-center px-2.5 py-1 text-[11px] uppercase tracking-[0.08em] font-semibold rounded-md" style="background: var(--accent-dim); border: 1px solid var(--accent-border); color: var(--accent);">
+                            {entry.work_type.as_deref().unwrap_or("Publication")}
+                        </span>
+                        {when!(let Some(published_on) = &entry.published_on =>
+                            <span>{published_on}</span>
+                        )}
+                        {when!(let Some(citations) = &citations =>
+                            <span><i class="fas fa-quote-right mr-1"></i>{citations}</span>
+                        )}
+                    </div>
+                    <h1 class="text-3xl md:text-5xl font-bold text-white leading-tight">
+                        {&entry.title}
+                    </h1>
+                    {when!(!entry.authors.is_empty() =>
+                        <p class="text-lg text-zinc-400 leading-relaxed">{entry.authors.join(", ")}</p>
+                    )}
+                    <div class="space-y-1 text-sm text-zinc-400">
+                        {when!(let Some(venue) = &entry.venue =>
+                            <p class="italic">{venue}</p>
+                        )}
+                        {when!(let Some(issue) = &issue =>
+                            <p>{issue}</p>
+                        )}
+                        {when!(let Some(doi) = &entry.doi =>
+                            <p>"DOI: "<a href={escape_xml(&format!("https://doi.org/{doi}"))} class="nav-link">{doi}</a></p>
+                        )}
+                    </div>
+                    {when!(let Some(url) = &url =>
+                        <div class="flex gap-3 flex-wrap">
+                            <a href={url.as_str()} target="_blank" rel="noopener noreferrer" class="btn-accent px-5 py-2 text-sm font-medium rounded-lg">
+                                <i class="fas fa-arrow-up-right-from-square mr-2"></i>"Read the publication"
+                            </a>
+                        </div>
+                    )}
+                    <div class="section-rule"></div>
+                </header>
+
+                {match &entry.summary {
+                    Some(summary) => rsx! {
+                        <section class="space-y-3 mb-10">
+                            <h2 class="text-xl font-semibold text-white">"Abstract"</h2>
+                            <p class="text-zinc-300 leading-relaxed">{summary}</p>
+                        </section>
+                    },
+                    None => rsx! {
+                        <p class="text-sm text-zinc-500 mb-10">"No abstract is available for this work."</p>
+                    },
+                }}
+
+                <CiteActions entry={entry.clone()} />
+            </article>
+        </PageLayout>
+    }
+}
@@ -1,6 +1,11 @@
 use crate::components::PageLayout;
+use crate::components::structured_data::{JsonLd, Organization, Person};
+use crate::orcid::{OrcidProfile, fetch_orcid_profile};
+use crate::publications::normalize_orcid_id;
+use crate::requests::log_warning;
+use crate::shared::escape_xml;
+use crate::shared::{BASE_URL, get_env};
 use axum::response::{Html, IntoResponse};
-use momenta::nodes::DefaultProps;
 use momenta::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +31,7 @@ pub struct ExperienceEntry {
     pub title: String,
     #[serde(rename = "type")]
     pub entry_type: String,
+    #[serde(default)]
     pub description: String,
 }
 
@@ -42,6 +48,16 @@ pub struct EducationEntry {
     pub school: String,
 }
 
+#[derive(Deserialize, Serialize, Clone)]
+pub struct FundingEntry {
+    pub period: String,
+    pub title: String,
+    /// e.g. `Grant` or `Award`.
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub funder: String,
+}
+
 #[derive(Deserialize, Serialize, Clone)]
 pub struct ResumeData {
     pub header: ResumeHeader,
@@ -49,23 +65,185 @@ pub struct ResumeData {
     pub skills: Vec<SkillEntry>,
     pub interests: Vec<String>,
     pub education: Vec<EducationEntry>,
+    #[serde(default)]
+    pub funding: Vec<FundingEntry>,
 }
 
-fn load_resume() -> ResumeData {
+pub fn load_resume() -> ResumeData {
     let json = include_str!("./resume.json");
     serde_json::from_str(json).expect("resume.json is valid")
 }
 
+/// `resume.json` with the ORCID profile merged in, or alone while ORCID is
+/// unreachable or no iD is set.
+pub async fn load_synced_resume() -> ResumeData {
+    let mut resume = load_resume();
+    let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+    if orcid_id.is_empty() {
+        return resume;
+    }
+    match fetch_orcid_profile(&orcid_id).await {
+        Ok(profile) => resume.merge_orcid(profile),
+        Err(error) => log_warning(&format!("fetching ORCID profile: {error}")),
+    }
+    resume
+}
+
+/// `2019 – 2023`, `2022 – Present` or a single year.
+fn period(start: Option<String>, end: Option<String>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) if start == end => start,
+        (Some(start), Some(end)) => format!("{start} – {end}"),
+        (Some(start), None) => format!("{start} – Present"),
+        (None, end) => end.unwrap_or_default(),
+    }
+}
+
+/// Whether either name contains the other, ignoring case, so "FUTA" matches
+/// "Federal University of Technology, Akure (FUTA), Nigeria".
+fn mentions(a: &str, b: &str) -> bool {
+    let (a, b) = (a.to_lowercase(), b.to_lowercase());
+    !a.is_empty() && !b.is_empty() && (a.contains(&b) || b.contains(&a))
+}
+
+impl ResumeData {
+    /// Adds what ORCID knows that `resume.json` doesn't. Entries already in
+    /// the file win: an employment, school or grant it mentions is skipped,
+    /// and its header is only filled where empty.
+    fn merge_orcid(&mut self, profile: OrcidProfile) {
+        if self.header.name.is_empty() {
+            self.header.name = profile.name.unwrap_or_default();
+        }
+        if self.header.bio.is_empty() {
+            self.header.bio = profile.biography.unwrap_or_default();
+        }
+        for (label, href) in profile.links {
+            let known = self
+                .header
+                .links
+                .iter()
+                .any(|link| link.href.trim_end_matches('/') == href.trim_end_matches('/'));
+            if !known {
+                self.header.links.push(ResumeLink {
+                    label,
+                    href,
+                    style: "ghost".to_string(),
+                    external: true,
+                });
+            }
+        }
+        for employment in profile.employments {
+            if self
+                .experience
+                .iter()
+                .any(|entry| mentions(&entry.title, &employment.organization))
+            {
+                continue;
+            }
+            self.experience.push(ExperienceEntry {
+                period: period(employment.start_year, employment.end_year),
+                title: match employment.role {
+                    Some(role) => format!("{role}, {}", employment.organization),
+                    None => employment.organization,
+                },
+                entry_type: employment
+                    .department
+                    .unwrap_or_else(|| "Employment".to_string()),
+                description: String::new(),
+            });
+        }
+        for education in profile.educations {
+            if self
+                .education
+                .iter()
+                .any(|entry| mentions(&entry.school, &education.organization))
+            {
+                continue;
+            }
+            self.education.push(EducationEntry {
+                period: period(education.start_year, education.end_year),
+                degree: education
+                    .role
+                    .or(education.department)
+                    .unwrap_or_else(|| "Studies".to_string()),
+                school: education.organization,
+            });
+        }
+        for funding in profile.fundings {
+            if self
+                .funding
+                .iter()
+                .any(|entry| entry.title.eq_ignore_ascii_case(&funding.title))
+            {
+                continue;
+            }
+            self.funding.push(FundingEntry {
+                period: period(funding.start_year, funding.end_year),
+                title: funding.title,
+                entry_type: funding
+                    .funding_type
+                    .unwrap_or_else(|| "Funding".to_string()),
+                funder: funding.funder.unwrap_or_default(),
+            });
+        }
+        for keyword in profile.keywords {
+            if !self
+                .interests
+                .iter()
+                .any(|interest| interest.eq_ignore_ascii_case(&keyword))
+            {
+                self.interests.push(keyword);
+            }
+        }
+    }
+}
+
+impl From<&ResumeData> for Person {
+    fn from(data: &ResumeData) -> Self {
+        Person {
+            name: data.header.name.clone(),
+            url: BASE_URL.to_string(),
+            description: Some(data.header.bio.clone()),
+            job_title: data
+                .experience
+                .first()
+                .and_then(|entry| entry.title.split(',').next())
+                .map(|title| title.trim().to_string()),
+            image: Some(format!("{BASE_URL}/icon.png")),
+            same_as: data
+                .header
+                .links
+                .iter()
+                .filter(|link| link.external)
+                .map(|link| link.href.clone())
+                .collect(),
+            knows_about: data
+                .skills
+                .iter()
+                .map(|skill| skill.category.clone())
+                .chain(data.interests.iter().cloned())
+                .collect(),
+            alumni_of: data
+                .education
+                .iter()
+                .map(|entry| Organization {
+                    name: entry.school.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
 pub async fn resume_handler() -> impl IntoResponse {
-    Html(ResumePage::render(&DefaultProps).to_string())
+    let data = load_synced_resume().await;
+    Html(ResumePage::render(&data).to_string())
 }
 
 #[component]
-pub fn ResumePage() -> Node {
-    let data = load_resume();
-
+pub fn ResumePage(data: &ResumeData) -> Node {
     rsx! {
-        <PageLayout title="Resume">
+        <PageLayout title="Resume" og_image={None} description={None} canonical={None} meta={Vec::new()}>
+            <JsonLd data={vec![Person::from(data).into()]} />
             <section class="py-4 md:py-8 space-y-12">
 
                 // Header
@@ -79,10 +257,11 @@ pub fn ResumePage() -> Node {
                                 "px-4 py-2 text-sm font-semibold rounded-md {}",
                                 if link.style == "accent" { "btn-accent" } else { "btn-ghost" }
                             );
+                            let href = escape_xml(&link.href);
                             if link.external {
-                                <a href={&link.href} class={&cls} target="_blank" rel="noopener noreferrer">{&link.label}</a>
+                                <a href={href} class={&cls} target="_blank" rel="noopener noreferrer">{&link.label}</a>
                             } else {
-                                <a href={&link.href} class={&cls}>{&link.label}</a>
+                                <a href={href} class={&cls}>{&link.label}</a>
                             }
                         })}
                     </div>
@@ -98,7 +277,9 @@ pub fn ResumePage() -> Node {
                                 <div>
                                     <p class="font-semibold text-zinc-100">{&entry.title}</p>
                                     <p class="text-sm" style="color: var(--accent);">{&entry.entry_type}</p>
-                                    <p class="mt-2 text-sm text-zinc-400 leading-relaxed">{&entry.description}</p>
+                                    {when!(!entry.description.is_empty() =>
+                                        <p class="mt-2 text-sm text-zinc-400 leading-relaxed">{&entry.description}</p>
+                                    )}
                                 </div>
                             </div>
                         })}
@@ -146,6 +327,27 @@ pub fn ResumePage() -> Node {
                     </div>
                 </div>
 
+                // Funding
+                {when!(!data.funding.is_empty() =>
+                    <div class="space-y-4">
+                        <h2 class="text-xl font-semibold text-white uppercase tracking-[0.1em]">"Funding"</h2>
+                        <div class="space-y-6">
+                            {data.funding.iter().map(|entry| {
+                                <div class="grid grid-cols-1 sm:grid-cols-[140px_1fr] gap-2 sm:gap-6">
+                                    <p class="text-sm text-zinc-500">{&entry.period}</p>
+                                    <div>
+                                        <p class="font-semibold text-zinc-100">{&entry.title}</p>
+                                        <p class="text-sm" style="color: var(--accent);">{&entry.entry_type}</p>
+                                        {when!(!entry.funder.is_empty() =>
+                                            <p class="text-sm text-zinc-400">{&entry.funder}</p>
+                                        )}
+                                    </div>
+                                </div>
+                            })}
+                        </div>
+                    </div>
+                )}
+
             </section>
         </PageLayout>
     }
@@ -0,0 +1,540 @@
+//! The single list of routes the site serves. The router, the navigation bar,
+//! the sitemap, `/robots.txt` and `/llms.txt` are all derived from [`ROUTES`],
+//! so adding a page only means adding an entry here.
+
+use super::error::AppError;
+use super::resume::page::load_resume;
+use super::{
+    adventures, assets, atom, blog, home, json_feed, newsletter, og, projects, publications,
+    resume, rss, sitemap, status,
+};
+use crate::requests::fetch_all_posts;
+use crate::shared::BASE_URL;
+use axum::response::{IntoResponse, Redirect};
+#[cfg(not(target_arch = "wasm32"))]
+use axum::routing::{MethodRouter, get, post};
+#[cfg(target_arch = "wasm32")]
+use axum::{
+    extract::{Path, Query},
+    http::HeaderMap,
+    response::Response,
+};
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture;
+#[cfg(target_arch = "wasm32")]
+use std::collections::HashMap;
+
+/// On native targets a route is a regular axum [`MethodRouter`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type Handler = fn() -> MethodRouter;
+
+/// On wasm, handlers that touch the network are `!Send` and cannot live in an
+/// axum router, so they are dispatched by hand from [`dispatch`]. `None` means
+/// the route is served before dispatch (see the POST handling in `lib.rs`).
+#[cfg(target_arch = "wasm32")]
+pub type Handler = Option<fn(WasmRequest) -> LocalBoxFuture<'static, Response>>;
+
+#[cfg(target_arch = "wasm32")]
+pub struct WasmRequest {
+    pub params: HashMap<String, String>,
+    pub headers: HeaderMap,
+    pub query: serde_json::Value,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl WasmRequest {
+    fn param(&self, name: &str) -> String {
+        self.params.get(name).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LastModified {
+    /// No meaningful date; the sitemap omits `<lastmod>`.
+    Unknown,
+    /// The page changes whenever an essay is published or updated.
+    NewestEssay,
+}
+
+#[derive(Clone, Copy)]
+pub struct SitemapHint {
+    pub priority: &'static str,
+    pub changefreq: &'static str,
+    pub lastmod: LastModified,
+}
+
+pub struct Route {
+    /// Path in axum syntax, e.g. `/essays/{slug}`.
+    pub path: &'static str,
+    pub handler: Handler,
+    /// Human-readable name used by the navigation bar and `/llms.txt`.
+    pub label: Option<&'static str>,
+    /// Whether the route shows up in the navigation bar.
+    pub nav: bool,
+    pub sitemap: Option<SitemapHint>,
+    /// Non-indexable routes are disallowed in `/robots.txt`.
+    pub indexable: bool,
+}
+
+impl Route {
+    const fn new(path: &'static str, handler: Handler) -> Self {
+        Self {
+            path,
+            handler,
+            label: None,
+            nav: false,
+            sitemap: None,
+            indexable: true,
+        }
+    }
+
+    const fn page(
+        path: &'static str,
+        handler: Handler,
+        label: &'static str,
+        priority: &'static str,
+        changefreq: &'static str,
+    ) -> Self {
+        Self {
+            label: Some(label),
+            sitemap: Some(SitemapHint {
+                priority,
+                changefreq,
+                lastmod: LastModified::Unknown,
+            }),
+            ..Self::new(path, handler)
+        }
+    }
+
+    const fn in_nav(mut self) -> Self {
+        self.nav = true;
+        self
+    }
+
+    const fn tracks_essays(mut self) -> Self {
+        if let Some(hint) = &mut self.sitemap {
+            hint.lastmod = LastModified::NewestEssay;
+        }
+        self
+    }
+
+    const fn hidden(mut self) -> Self {
+        self.indexable = false;
+        self
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+macro_rules! handler {
+    (|$req:ident| $body:expr, native: $native:expr) => {
+        || $native
+    };
+}
+
+#[cfg(target_arch = "wasm32")]
+macro_rules! handler {
+    (|$req:ident| $body:expr, native: $native:expr) => {
+        Some(|$req: WasmRequest| -> LocalBoxFuture<'static, Response> {
+            Box::pin(async move { $body.into_response() })
+        })
+    };
+}
+
+macro_rules! redirect {
+    ($kind:ident, $to:expr) => {
+        handler!(
+            |_req| Redirect::$kind($to),
+            native: get(|| async { Redirect::$kind($to) })
+        )
+    };
+}
+
+pub static ROUTES: &[Route] = &[
+    Route::page(
+        "/",
+        handler!(|_req| home::home_handler().await, native: get(home::home_handler)),
+        "Home",
+        "1.0",
+        "daily",
+    )
+    .tracks_essays(),
+    Route::page(
+        "/projects",
+        handler!(
+            |req| projects::page::projects_handler(Query(req.query)).await,
+            native: get(projects::page::projects_handler)
+        ),
+        "Projects",
+        "0.8",
+        "weekly",
+    )
+    .in_nav(),
+    Route::new(
+        "/projects/infinite_scroll",
+        handler!(
+            |req| projects::page::infinite_scroll(Query(req.query)).await,
+            native: get(projects::page::infinite_scroll)
+        ),
+    )
+    .hidden(),
+    Route::page(
+        "/projects/stats",
+        handler!(
+            |_req| projects::stats::project_stats_handler().await,
+            native: get(projects::stats::project_stats_handler)
+        ),
+        "Project Stats",
+        "0.6",
+        "weekly",
+    ),
+    Route::new(
+        "/projects/{slug}/cover.svg",
+        handler!(
+            |req| {
+                let slug = req.param("slug");
+                projects::cover::cover_handler(Path(crate::shared::PageParams { slug })).await
+            },
+            native: get(projects::cover::cover_handler)
+        ),
+    ),
+    Route::new(
+        "/projects/{slug}",
+        handler!(
+            |req| {
+                let slug = req.param("slug");
+                projects::slug::project_detail_handler(Path(crate::shared::PageParams { slug })).await
+            },
+            native: get(projects::slug::project_detail_handler)
+        ),
+    ),
+    Route::page(
+        "/essays",
+        handler!(
+            |req| blog::page::blog_handler(Query(req.query)).await,
+            native: get(blog::page::blog_handler)
+        ),
+        "Essays",
+        "0.9",
+        "daily",
+    )
+    .in_nav()
+    .tracks_essays(),
+    Route::new(
+        "/essays/infinite_scroll",
+        handler!(
+            |req| blog::page::infinite_scroll(Query(req.query)).await,
+            native: get(blog::page::infinite_scroll)
+        ),
+    )
+    .hidden(),
+    Route::new(
+        "/essays/{slug}",
+        handler!(
+            |req| {
+                let slug = req.param("slug");
+                blog::slug::blog_detail_handler(Path(crate::shared::PageParams { slug }), req.headers)
+                    .await
+            },
+            native: get(blog::slug::blog_detail_handler)
+        ),
+    ),
+    Route::page(
+        "/publications",
+        handler!(
+            |req| publications::page::publications_handler(Query(req.query)).await,
+            native: get(publications::page::publications_handler)
+        ),
+        "Publications",
+        "0.8",
+        "monthly",
+    )
+    .in_nav(),
+    Route::new(
+        "/publications/filter",
+        handler!(
+            |req| publications::page::filter_handler(Query(req.query)).await,
+            native: get(publications::page::filter_handler)
+        ),
+    )
+    .hidden(),
+    Route::new(
+        "/publications/export/{format}",
+        handler!(
+            |req| publications::page::export_handler(Path(publications::page::ExportParams {
+                format: req.param("format"),
+            }))
+            .await,
+            native: get(publications::page::export_handler)
+        ),
+    ),
+    Route::new(
+        "/publications/{slug}/export/{format}",
+        handler!(
+            |req| publications::page::work_export_handler(Path(publications::page::WorkExportParams {
+                slug: req.param("slug"),
+                format: req.param("format"),
+            }))
+            .await,
+            native: get(publications::page::work_export_handler)
+        ),
+    ),
+    Route::new(
+        "/publications/{slug}",
+        handler!(
+            |req| {
+                let slug = req.param("slug");
+                publications::slug::publication_detail_handler(Path(crate::shared::PageParams { slug }))
+                    .await
+            },
+            native: get(publications::slug::publication_detail_handler)
+        ),
+    ),
+    Route::page(
+        "/resume",
+        handler!(
+            |_req| resume::page::resume_handler().await,
+            native: get(resume::page::resume_handler)
+        ),
+        "Resume",
+        "0.7",
+        "monthly",
+    )
+    .in_nav(),
+    Route::page(
+        "/adventures",
+        handler!(
+            |req| adventures::page::adventures_handler(Query(req.query)).await,
+            native: get(adventures::page::adventures_handler)
+        ),
+        "Timeline",
+        "0.7",
+        "weekly",
+    )
+    .in_nav(),
+    Route::new(
+        "/adventures/filter",
+        handler!(
+            |req| adventures::page::filter_handler(Query(req.query)).await,
+            native: get(adventures::page::filter_handler)
+        ),
+    )
+    .hidden(),
+    Route::page(
+        "/newsletter",
+        handler!(
+            |_req| newsletter::newsletter_get_handler().await,
+            native: get(newsletter::newsletter_get_handler).post(newsletter::newsletter_post_handler)
+        ),
+        "Newsletter",
+        "0.6",
+        "monthly",
+    ),
+    #[cfg(not(target_arch = "wasm32"))]
+    Route::new("/newsletter/send", || {
+        post(newsletter::newsletter_send_handler)
+    })
+    .hidden(),
+    #[cfg(target_arch = "wasm32")]
+    Route::new("/newsletter/send", None).hidden(),
+    Route::new(
+        "/og/{kind}/{file}",
+        handler!(
+            |req| og::og_card_handler(
+                Path(og::OgParams {
+                    kind: req.param("kind"),
+                    file: req.param("file"),
+                }),
+                req.headers,
+            )
+            .await,
+            native: get(og::og_card_handler)
+        ),
+    ),
+    Route::new(
+        "/rss.xml",
+        handler!(|_req| rss::rss_handler().await, native: get(rss::rss_handler)),
+    ),
+    Route::new(
+        "/atom.xml",
+        handler!(|_req| atom::atom_handler().await, native: get(atom::atom_handler)),
+    ),
+    Route::new(
+        "/feed.json",
+        handler!(
+            |_req| json_feed::json_feed_handler().await,
+            native: get(json_feed::json_feed_handler)
+        ),
+    ),
+    Route::new(
+        "/sitemap.xml",
+        handler!(|_req| sitemap::sitemap_handler().await, native: get(sitemap::sitemap_handler)),
+    ),
+    Route::new(
+        "/sitemaps/{file}",
+        handler!(
+            |req| sitemap::sitemap_section_handler(Path(req.param("file"))).await,
+            native: get(sitemap::sitemap_section_handler)
+        ),
+    ),
+    Route::new(
+        "/status.json",
+        handler!(|req| status::status_handler(req.headers).await, native: get(status::status_handler)),
+    )
+    .hidden(),
+    Route::new(
+        "/robots.txt",
+        handler!(|_req| robots_handler().await, native: get(robots_handler)),
+    ),
+    Route::new(
+        "/llms.txt",
+        handler!(|_req| llms_handler().await, native: get(llms_handler)),
+    ),
+    Route::new(
+        "/styles.css",
+        handler!(|_req| assets::styles_handler().await, native: get(assets::styles_handler)),
+    ),
+    Route::new(
+        "/icon.png",
+        handler!(|_req| assets::favicon_handler().await, native: get(assets::favicon_handler)),
+    ),
+    Route::new(
+        "/og.png",
+        handler!(|_req| assets::og_image_handler().await, native: get(assets::og_image_handler)),
+    ),
+    Route::new("/blog", redirect!(permanent, "/essays")),
+    Route::new("/mods/resume", redirect!(permanent, "/resume")),
+    Route::new("/mods/connect", redirect!(permanent, "/connect")),
+    Route::new(
+        "/connect",
+        redirect!(temporary, "https://cal.com/elcharitas"),
+    ),
+    Route::new(
+        "/resume.docx",
+        redirect!(
+            temporary,
+            "https://docs.google.com/document/d/e/2PACX-1vRxPZLfm3NkCml3MhdrnEIBjTBzhRvDznOyDYF19SGUlMaijecJ8oAtcFI-dnp49vC3Ndt2NJvlssNA/pub?embedded=true"
+        ),
+    ),
+];
+
+/// Routes shown in the navigation bar, in registry order.
+pub fn navigation() -> impl Iterator<Item = &'static Route> {
+    ROUTES.iter().filter(|route| route.nav)
+}
+
+/// Routes listed in the pages sitemap.
+pub fn sitemap_pages() -> impl Iterator<Item = (&'static Route, SitemapHint)> {
+    ROUTES
+        .iter()
+        .filter(|route| route.indexable)
+        .filter_map(|route| route.sitemap.map(|hint| (route, hint)))
+}
+
+/// Matches a request path against an axum-style pattern, returning the
+/// captured `{name}` segments.
+#[cfg(target_arch = "wasm32")]
+fn match_path(pattern: &str, path: &str) -> Option<HashMap<String, String>> {
+    let mut params = HashMap::new();
+    let mut pattern_segments = pattern.split('/');
+    let mut path_segments = path.split('/');
+    loop {
+        match (pattern_segments.next(), path_segments.next()) {
+            (None, None) => return Some(params),
+            (Some(expected), Some(actual)) => {
+                match expected
+                    .strip_prefix('{')
+                    .and_then(|name| name.strip_suffix('}'))
+                {
+                    Some(name) if !actual.is_empty() => {
+                        params.insert(name.to_string(), actual.to_string());
+                    }
+                    None if expected == actual => {}
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Serves a GET request from the registry. Static paths are listed before the
+/// parameterised ones that would also match them, so the first hit wins.
+#[cfg(target_arch = "wasm32")]
+pub async fn dispatch(
+    path: &str,
+    headers: HeaderMap,
+    query: serde_json::Value,
+) -> Option<Response> {
+    let (handler, params) = ROUTES
+        .iter()
+        .find_map(|route| Some((route.handler?, match_path(route.path, path)?)))?;
+    Some(
+        handler(WasmRequest {
+            params,
+            headers,
+            query,
+        })
+        .await,
+    )
+}
+
+pub async fn robots_handler() -> impl IntoResponse {
+    let mut body = String::from("User-agent: *\nAllow: /\n");
+    for route in ROUTES.iter().filter(|route| !route.indexable) {
+        body.push_str(&format!("Disallow: {}\n", route.path));
+    }
+    body.push_str(&format!("\nSitemap: {BASE_URL}/sitemap.xml\n"));
+
+    (
+        [
+            ("Content-Type", "text/plain; charset=utf-8"),
+            ("Cache-Control", "public, max-age=86400"),
+        ],
+        body,
+    )
+}
+
+/// An [llms.txt](https://llmstxt.org) index of the site's pages and essays.
+pub async fn llms_handler() -> Result<impl IntoResponse, AppError> {
+    let mut body = format!(
+        "# elcharitas.wtf\n\n\
+         > Essays, projects and publications by {} on software development, \
+         product decisions, and the realities of shipping.\n\n## Pages\n\n",
+        load_resume().header.name
+    );
+    for (route, _) in sitemap_pages() {
+        if let Some(label) = route.label {
+            body.push_str(&format!("- [{label}]({BASE_URL}{})\n", route.path));
+        }
+    }
+
+    let posts = fetch_all_posts().await?;
+    if !posts.is_empty() {
+        body.push_str("\n## Essays\n\n");
+        for post in posts {
+            body.push_str(&format!(
+                "- [{}]({BASE_URL}/essays/{}): {}\n",
+                post.title, post.slug, post.brief
+            ));
+        }
+    }
+
+    body.push_str(&format!(
+        "\n## Optional\n\n- [RSS feed]({BASE_URL}/rss.xml)\n- [Sitemap]({BASE_URL}/sitemap.xml)\n"
+    ));
+
+    Ok((
+        [
+            ("Content-Type", "text/plain; charset=utf-8"),
+            ("Cache-Control", "public, max-age=3600"),
+        ],
+        body,
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn register(router: axum::Router) -> axum::Router {
+    ROUTES.iter().fold(router, |router, route| {
+        router.route(route.path, (route.handler)())
+    })
+}
@@ -1,56 +1,104 @@
-use crate::requests::fetch_all_posts;
+use super::error::AppError;
+use super::resume::page::load_resume;
+use crate::requests::fetch_all_posts_with_content;
+use crate::shared::xml_elements::{AtomLink, ContentEncoded, DcCreator, MediaContent, Rss};
 use crate::shared::*;
 use axum::response::IntoResponse;
 use chrono::Utc;
 use momenta::prelude::*;
 use serde::{Deserialize, Serialize};
 
+const DEFAULT_ITEM_LIMIT: usize = 50;
+
 #[derive(Default, Serialize, Deserialize)]
 pub struct RSSProps {
     pub posts: Vec<Post>,
+    pub author: String,
+}
+
+/// The posts every feed carries: newest first, at most `RSS_ITEM_LIMIT` of
+/// them.
+pub async fn feed_posts() -> Result<Vec<Post>, AppError> {
+    let limit = get_env("RSS_ITEM_LIMIT")
+        .parse()
+        .unwrap_or(DEFAULT_ITEM_LIMIT);
+
+    let mut posts = fetch_all_posts_with_content().await?;
+    posts.sort_by_key(|post| std::cmp::Reverse(post.published()));
+    posts.truncate(limit);
+    Ok(posts)
 }
 
 impl RSSProps {
-    async fn load() -> Self {
-        Self {
-            posts: fetch_all_posts().await,
-        }
+    async fn load() -> Result<Self, AppError> {
+        Ok(Self {
+            posts: feed_posts().await?,
+            author: load_resume().header.name,
+        })
     }
 }
 
-pub async fn rss_handler() -> impl IntoResponse {
-    let props = RSSProps::load().await;
-    (
+pub async fn rss_handler() -> Result<impl IntoResponse, AppError> {
+    let props = RSSProps::load().await?;
+    Ok((
         [
             ("Content-Type", "application/rss+xml; charset=utf-8"),
             ("Cache-Control", "public, max-age=3600"),
         ],
         RSSPage::render(&props).to_string(),
-    )
+    ))
 }
 
 #[component]
 pub fn RSSPage(props: &RSSProps) -> Node {
     use xml_elements as momenta;
-    let now = Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+    let last_build = props
+        .posts
+        .iter()
+        .filter_map(Post::updated)
+        .max()
+        .unwrap_or_else(Utc::now)
+        .to_rfc2822();
 
     rsx! {
-        <rss>
+        <Rss
+            version="2.0"
+            xmlns_atom="http://www.w3.org/2005/Atom"
+            xmlns_content="http://purl.org/rss/1.0/modules/content/"
+            xmlns_dc="http://purl.org/dc/elements/1.1/"
+            xmlns_media="http://search.yahoo.com/mrss/"
+        >
             <channel>
                 <title>"elcharitas.wtf — Essays"</title>
                 <link>"https://elcharitas.wtf/essays"</link>
                 <description>"Software development, product decisions, and the realities of shipping."</description>
-                <lastBuildDate>{&now}</lastBuildDate>
-                {&props.posts.iter().map(|post| {
+                <language>"en-US"</language>
+                <AtomLink href={format!("{BASE_URL}/rss.xml")} rel="self" type="application/rss+xml" />
+                <lastBuildDate>{&last_build}</lastBuildDate>
+                {props.posts.iter().map(|post| {
                     <item>
                         <title>{&post.title}</title>
                         <link>{&post.url}</link>
-                        <guid>{&post.url}</guid>
+                        <guid isPermaLink="true">{&post.url}</guid>
                         <description>{&post.brief}</description>
-                        <pubDate>{&now}</pubDate>
+                        {when!(let Some(content) = &post.content =>
+                            <ContentEncoded>{&content.html}</ContentEncoded>
+                        )}
+                        <DcCreator>{&props.author}</DcCreator>
+                        {post.tags.iter().map(|tag| {
+                            <category>{&tag.name}</category>
+                        })}
+                        // An <enclosure> must state the file's size, which would
+                        // cost a request per cover; media:content needs none.
+                        {when!(let Some(cover) = post.cover_image_url() =>
+                            <MediaContent url={escape_xml(cover)} medium="image" type={image_mime_type(cover)} />
+                        )}
+                        {when!(let Some(published) = post.published() =>
+                            <pubDate>{published.to_rfc2822()}</pubDate>
+                        )}
                     </item>
                 })}
             </channel>
-        </rss>
+        </Rss>
     }
 }
@@ -1,78 +1,319 @@
-use crate::requests::fetch_all_posts;
+use super::error::AppError;
+use super::routes::{LastModified, sitemap_pages};
+use crate::cache::TtlCache;
+use crate::publications::{fetch_publications, normalize_orcid_id};
+use crate::requests::{fetch_all_posts, get_every_project, log_warning};
+use crate::shared::xml_elements::{ImageImage, ImageLoc, UrlSet};
 use crate::shared::*;
-use axum::response::IntoResponse;
-use chrono::Utc;
+use axum::{extract::Path, response::IntoResponse};
+use chrono::{DateTime, SecondsFormat, Utc};
 use momenta::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
-pub struct SitemapProps {
-    pub posts: Vec<Post>,
-    pub base_url: String,
+/// The sitemap protocol caps a single file at 50,000 URLs.
+const MAX_URLS_PER_SITEMAP: usize = 50_000;
+const MAX_PROJECT_PAGES: u32 = 20;
+/// How long a section's entries are reused, matching the `Cache-Control` the
+/// sitemap responses send.
+const SITEMAP_TTL: i64 = 3600;
+
+lazy_static::lazy_static! {
+    /// Entries by section, shared by the index and the section files so the
+    /// index doesn't rebuild every section on each request.
+    static ref SECTION_ENTRIES: TtlCache<SitemapSection, Vec<SitemapEntry>> =
+        TtlCache::new(SITEMAP_TTL);
 }
 
-impl Default for SitemapProps {
-    fn default() -> Self {
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SitemapEntry {
+    pub loc: String,
+    pub lastmod: Option<String>,
+    pub changefreq: Option<String>,
+    pub priority: Option<String>,
+    pub images: Vec<String>,
+}
+
+impl SitemapEntry {
+    fn new(path: &str, changefreq: &str, priority: &str) -> Self {
         Self {
-            posts: Vec::new(),
-            base_url: String::from("https://elcharitas.wtf"),
+            loc: format!("{BASE_URL}{path}"),
+            lastmod: None,
+            changefreq: Some(changefreq.to_string()),
+            priority: Some(priority.to_string()),
+            images: Vec::new(),
+        }
+    }
+
+    fn lastmod(mut self, date: Option<DateTime<Utc>>) -> Self {
+        self.lastmod = date.map(format_lastmod);
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum SitemapSection {
+    Pages,
+    Essays,
+    Projects,
+    Publications,
+    Tags,
+}
+
+impl SitemapSection {
+    const ALL: [SitemapSection; 5] = [
+        SitemapSection::Pages,
+        SitemapSection::Essays,
+        SitemapSection::Projects,
+        SitemapSection::Publications,
+        SitemapSection::Tags,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            SitemapSection::Pages => "pages",
+            SitemapSection::Essays => "essays",
+            SitemapSection::Projects => "projects",
+            SitemapSection::Publications => "publications",
+            SitemapSection::Tags => "tags",
         }
     }
+
+    async fn entries(self) -> Result<Vec<SitemapEntry>, AppError> {
+        SECTION_ENTRIES
+            .get_or_refresh(self, self.load_entries())
+            .await
+    }
+
+    async fn load_entries(self) -> Result<Vec<SitemapEntry>, AppError> {
+        Ok(match self {
+            SitemapSection::Pages => {
+                let newest_post = fetch_all_posts()
+                    .await?
+                    .iter()
+                    .filter_map(Post::updated)
+                    .max();
+                sitemap_pages()
+                    .map(|(route, hint)| {
+                        let lastmod = match hint.lastmod {
+                            LastModified::NewestEssay => newest_post,
+                            LastModified::Unknown => None,
+                        };
+                        SitemapEntry::new(route.path, hint.changefreq, hint.priority)
+                            .lastmod(lastmod)
+                    })
+                    .collect()
+            }
+            SitemapSection::Essays => fetch_all_posts()
+                .await?
+                .iter()
+                .map(|post| SitemapEntry {
+                    images: post
+                        .cover_image_url()
+                        .map(str::to_string)
+                        .into_iter()
+                        .collect(),
+                    ..SitemapEntry::new(&format!("/essays/{}", post.slug), "monthly", "0.7")
+                        .lastmod(post.updated())
+                })
+                .collect(),
+            SitemapSection::Projects => get_every_project(MAX_PROJECT_PAGES)
+                .await?
+                .iter()
+                .map(|project| SitemapEntry {
+                    images: vec![project.cover()],
+                    ..SitemapEntry::new(&format!("/projects/{}", project.name), "weekly", "0.6")
+                        .lastmod(parse_date(&project.updated_at))
+                })
+                .collect(),
+            SitemapSection::Publications => {
+                let orcid_id = normalize_orcid_id(&get_env("ORCID_ID"));
+                fetch_publications(&orcid_id)
+                    .await?
+                    .iter()
+                    .map(|entry| {
+                        SitemapEntry::new(&format!("/publications/{}", entry.id), "yearly", "0.6")
+                            .lastmod(entry.published_on.as_deref().and_then(parse_date))
+                    })
+                    .collect()
+            }
+            SitemapSection::Tags => {
+                let posts = fetch_all_posts().await?;
+                let mut tags: Vec<(&Tag, Option<DateTime<Utc>>)> = Vec::new();
+                for post in &posts {
+                    for tag in &post.tags {
+                        match tags.iter_mut().find(|(seen, _)| seen.slug == tag.slug) {
+                            Some((_, newest)) => *newest = (*newest).max(post.updated()),
+                            None => tags.push((tag, post.updated())),
+                        }
+                    }
+                }
+                tags.into_iter()
+                    .map(|(tag, newest)| {
+                        let query = PostQuery {
+                            tag: Some(tag.slug.clone()),
+                            search: None,
+                        };
+                        SitemapEntry::new(&query.href(1), "weekly", "0.5").lastmod(newest)
+                    })
+                    .collect()
+            }
+        })
+    }
+}
+
+fn format_lastmod(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// Child sitemap file names: the first chunk of a section is `{name}.xml`,
+/// later chunks are `{name}-2.xml`, `{name}-3.xml` and so on.
+fn chunk_file_name(section: SitemapSection, chunk: usize) -> String {
+    match chunk {
+        0 => format!("{}.xml", section.name()),
+        _ => format!("{}-{}.xml", section.name(), chunk + 1),
+    }
 }
 
-impl SitemapProps {
+fn parse_chunk_file_name(file: &str) -> Option<(SitemapSection, usize)> {
+    let stem = file.strip_suffix(".xml")?;
+    let (name, chunk) = match stem.rsplit_once('-') {
+        Some((name, number)) => (name, number.parse::<usize>().ok()?.checked_sub(1)?),
+        None => (stem, 0),
+    };
+    let section = SitemapSection::ALL
+        .into_iter()
+        .find(|section| section.name() == name)?;
+    Some((section, chunk))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SitemapReference {
+    pub loc: String,
+    pub lastmod: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct SitemapIndexProps {
+    pub sitemaps: Vec<SitemapReference>,
+}
+
+impl SitemapIndexProps {
+    /// References every section that loads; one whose upstream is down is
+    /// left out rather than failing the whole index.
     async fn load() -> Self {
-        Self {
-            posts: fetch_all_posts().await,
-            base_url: "https://elcharitas.wtf".to_string(),
+        let mut sitemaps = Vec::new();
+        for section in SitemapSection::ALL {
+            let entries = match section.entries().await {
+                Ok(entries) => entries,
+                Err(error) => {
+                    log_warning(&format!(
+                        "leaving the {} sitemap out of the index: {error}",
+                        section.name()
+                    ));
+                    continue;
+                }
+            };
+            for (chunk, entries) in entries.chunks(MAX_URLS_PER_SITEMAP).enumerate() {
+                sitemaps.push(SitemapReference {
+                    loc: format!("{BASE_URL}/sitemaps/{}", chunk_file_name(section, chunk)),
+                    lastmod: entries
+                        .iter()
+                        .filter_map(|entry| entry.lastmod.clone())
+                        .max(),
+                });
+            }
         }
+        Self { sitemaps }
     }
 }
 
-pub async fn sitemap_handler() -> impl IntoResponse {
-    let props = SitemapProps::load().await;
-    (
+#[derive(Default, Serialize, Deserialize)]
+pub struct SitemapProps {
+    pub entries: Vec<SitemapEntry>,
+}
+
+pub async fn sitemap_handler() -> Result<impl IntoResponse, AppError> {
+    let props = SitemapIndexProps::load().await;
+    Ok((
+        [
+            ("Content-Type", "application/xml; charset=utf-8"),
+            ("Cache-Control", "public, max-age=3600"),
+        ],
+        SitemapIndexPage::render(&props).to_string(),
+    ))
+}
+
+pub async fn sitemap_section_handler(
+    Path(file): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let (section, chunk) = parse_chunk_file_name(&file).ok_or(AppError::NotFound)?;
+    let entries: Vec<SitemapEntry> = section
+        .entries()
+        .await?
+        .into_iter()
+        .skip(chunk * MAX_URLS_PER_SITEMAP)
+        .take(MAX_URLS_PER_SITEMAP)
+        .collect();
+    if entries.is_empty() && chunk > 0 {
+        return Err(AppError::NotFound);
+    }
+
+    let props = SitemapProps { entries };
+    Ok((
         [
             ("Content-Type", "application/xml; charset=utf-8"),
             ("Cache-Control", "public, max-age=3600"),
         ],
         SitemapPage::render(&props).to_string(),
-    )
+    ))
 }
 
 #[component]
-pub fn SitemapPage(props: &SitemapProps) -> Node {
+pub fn SitemapIndexPage(props: &SitemapIndexProps) -> Node {
     use xml_elements as momenta;
-    let now = Utc::now().format("%Y-%m-%dT%H:%M:%S+00:00").to_string();
-
-    let app_pages = vec![
-        ("/", "1.0", "daily"),
-        ("/essays", "0.9", "daily"),
-        ("/projects", "0.8", "weekly"),
-        ("/publications", "0.8", "monthly"),
-        ("/resume", "0.7", "monthly"),
-        ("/adventures", "0.7", "weekly"),
-        ("/newsletter", "0.6", "monthly"),
-    ];
 
     rsx! {
-        <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
-            {app_pages.iter().map(|(path, priority, changefreq)| {
-                <url>
-                    <loc>{format!("{}{}", &props.base_url, path)}</loc>
-                    <lastmod>{&now}</lastmod>
-                    <changefreq>{changefreq}</changefreq>
-                    <priority>{priority}</priority>
-                </url>
+        <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            {props.sitemaps.iter().map(|sitemap| {
+                <sitemap>
+                    <loc>{&sitemap.loc}</loc>
+                    {when!(let Some(lastmod) = &sitemap.lastmod =>
+                        <lastmod>{lastmod}</lastmod>
+                    )}
+                </sitemap>
             })}
-            {props.posts.iter().map(|post| {
+        </sitemapindex>
+    }
+}
+
+#[component]
+pub fn SitemapPage(props: &SitemapProps) -> Node {
+    use xml_elements as momenta;
+
+    rsx! {
+        <UrlSet
+            xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
+            xmlns_image="http://www.google.com/schemas/sitemap-image/1.1"
+        >
+            {props.entries.iter().map(|entry| {
                 <url>
-                    <loc>{format!("{}/essays/{}", &props.base_url, &post.slug)}</loc>
-                    <lastmod>{&now}</lastmod>
-                    <changefreq>"monthly"</changefreq>
-                    <priority>"0.7"</priority>
+                    <loc>{&entry.loc}</loc>
+                    {when!(let Some(lastmod) = &entry.lastmod =>
+                        <lastmod>{lastmod}</lastmod>
+                    )}
+                    {when!(let Some(changefreq) = &entry.changefreq =>
+                        <changefreq>{changefreq}</changefreq>
+                    )}
+                    {when!(let Some(priority) = &entry.priority =>
+                        <priority>{priority}</priority>
+                    )}
+                    {entry.images.iter().map(|image| {
+                        <ImageImage>
+                            <ImageLoc>{image}</ImageLoc>
+                        </ImageImage>
+                    })}
                 </url>
             })}
-        </urlset>
+        </UrlSet>
     }
 }
@@ -0,0 +1,59 @@
+use crate::requests::{RateLimit, github_rate_limits};
+use crate::shared::get_env;
+use axum::http::{HeaderMap, header};
+use axum::response::IntoResponse;
+use chrono::Utc;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Status {
+    /// `up`, or `degraded` while a GitHub quota is used up and pages are
+    /// served from stale copies.
+    pub status: &'static str,
+    /// Only sent to requests carrying the admin token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github: Option<GitHubStatus>,
+}
+
+#[derive(Serialize)]
+pub struct GitHubStatus {
+    pub authenticated: bool,
+    /// Each quota seen so far; empty until the first API response of this
+    /// process.
+    pub rate_limits: Vec<RateLimit>,
+}
+
+/// Whether the request carries `NEWSLETTER_SEND_TOKEN` as a bearer token, the
+/// one credential the site's operator already holds.
+fn is_admin(headers: &HeaderMap) -> bool {
+    let token = get_env("NEWSLETTER_SEND_TOKEN");
+    let auth = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("");
+    !token.is_empty() && auth == format!("Bearer {token}")
+}
+
+/// Whether the site is up or degraded for anyone, plus upstream quotas and
+/// whether credentials are configured for whoever runs it.
+pub async fn status_handler(headers: HeaderMap) -> impl IntoResponse {
+    let rate_limits = github_rate_limits();
+    let now = Utc::now();
+    let exhausted = rate_limits
+        .iter()
+        .any(|limit| limit.remaining == 0 && limit.reset_at > now);
+    let status = Status {
+        status: if exhausted { "degraded" } else { "up" },
+        github: is_admin(&headers).then(|| GitHubStatus {
+            authenticated: !get_env("GITHUB_TOKEN").is_empty(),
+            rate_limits,
+        }),
+    };
+    (
+        [
+            ("Content-Type", "application/json; charset=utf-8"),
+            ("Cache-Control", "no-store"),
+        ],
+        serde_json::to_string_pretty(&status).unwrap_or_default(),
+    )
+}
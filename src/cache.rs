@@ -0,0 +1,73 @@
+//! The in-memory cache behind every upstream fetcher: values expire after a
+//! fixed time but are kept past it, so an outage serves the last copy rather
+//! than an error.
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+
+/// Values by key, each fresh for `ttl` seconds after it was stored. Caches
+/// holding a single value use `()` as the key.
+pub struct TtlCache<K, V> {
+    ttl: i64,
+    entries: Mutex<HashMap<K, (V, i64)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub fn new(ttl: i64) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The value for `key` if it has not expired.
+    pub fn fresh(&self, key: &K) -> Option<V> {
+        let now = Utc::now().timestamp();
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .filter(|(_, expiry)| now < *expiry)
+            .map(|(value, _)| value.clone())
+    }
+
+    /// The value for `key`, expired or not.
+    pub fn stale(&self, key: &K) -> Option<V> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .map(|(value, _)| value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let expiry = Utc::now().timestamp() + self.ttl;
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, (value, expiry));
+    }
+
+    /// The fresh value for `key`, or else the one `load` produces. When `load`
+    /// fails the expired value is served in its place, and the error only
+    /// when there is none.
+    pub async fn get_or_refresh<E>(
+        &self,
+        key: K,
+        load: impl Future<Output = Result<V, E>>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.fresh(&key) {
+            return Ok(value);
+        }
+        match load.await {
+            Ok(value) => {
+                self.insert(key, value.clone());
+                Ok(value)
+            }
+            Err(error) => self.stale(&key).ok_or(error),
+        }
+    }
+}
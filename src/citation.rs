@@ -0,0 +1,733 @@
+//! Citations for publications: BibTeX, RIS and CSL-JSON exports for
+//! reference managers, and APA, MLA and Chicago strings to copy.
+
+use crate::publications::PublicationEntry;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CitationFormat {
+    BibTex,
+    Ris,
+    CslJson,
+}
+
+impl CitationFormat {
+    pub const ALL: [Self; 3] = [Self::BibTex, Self::Ris, Self::CslJson];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "bibtex" => Some(Self::BibTex),
+            "ris" => Some(Self::Ris),
+            "csl-json" => Some(Self::CslJson),
+            _ => None,
+        }
+    }
+
+    /// The name used in export URLs.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BibTex => "bibtex",
+            Self::Ris => "ris",
+            Self::CslJson => "csl-json",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::BibTex => "BibTeX",
+            Self::Ris => "RIS",
+            Self::CslJson => "CSL-JSON",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::BibTex => "application/x-bibtex; charset=utf-8",
+            Self::Ris => "application/x-research-info-systems; charset=utf-8",
+            Self::CslJson => "application/vnd.citationstyles.csl+json; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::BibTex => "bib",
+            Self::Ris => "ris",
+            Self::CslJson => "json",
+        }
+    }
+
+    pub fn export(self, entries: &[PublicationEntry]) -> String {
+        match self {
+            Self::BibTex => bibtex(entries),
+            Self::Ris => entries.iter().map(ris).collect(),
+            Self::CslJson => {
+                let items: Vec<CslItem> = entries.iter().map(CslItem::from).collect();
+                serde_json::to_string_pretty(&items).unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// What a work is, as far as citation styles care.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Article,
+    Conference,
+    Book,
+    Chapter,
+    Thesis,
+    Preprint,
+    Presentation,
+    Other,
+}
+
+impl Kind {
+    /// From the humanized ORCID, Crossref or OpenAlex type, or a hand-written
+    /// one such as "Poster".
+    fn of(entry: &PublicationEntry) -> Self {
+        let work_type = entry
+            .work_type
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase();
+        let has = |words: &[&str]| words.iter().any(|word| work_type.contains(word));
+        if has(&["conference", "proceedings"]) {
+            Self::Conference
+        } else if has(&["chapter"]) {
+            Self::Chapter
+        } else if has(&["book", "monograph"]) {
+            Self::Book
+        } else if has(&["thesis", "dissertation"]) {
+            Self::Thesis
+        } else if has(&["preprint", "posted content"]) {
+            Self::Preprint
+        } else if has(&["talk", "poster", "lecture", "presentation"]) {
+            Self::Presentation
+        } else if has(&["article", "journal", "review", "letter"]) {
+            Self::Article
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// An author's given and family names. Names are stored given names first;
+/// a comma means they were written family name first.
+struct Name<'a> {
+    given: &'a str,
+    family: &'a str,
+}
+
+impl<'a> Name<'a> {
+    fn parse(name: &'a str) -> Self {
+        let name = name.trim();
+        let (given, family) = match name.split_once(',') {
+            Some((family, given)) => (given.trim(), family.trim()),
+            None => name
+                .rsplit_once(' ')
+                .map_or(("", name), |(given, family)| (given.trim(), family)),
+        };
+        Self { given, family }
+    }
+
+    /// `Lovelace, A. K.`
+    fn initialed(&self) -> String {
+        let initials: Vec<String> = self
+            .given
+            .split([' ', '-'])
+            .filter_map(|part| part.chars().next())
+            .map(|initial| format!("{initial}."))
+            .collect();
+        match initials.is_empty() {
+            true => self.family.to_string(),
+            false => format!("{}, {}", self.family, initials.join(" ")),
+        }
+    }
+
+    /// `Lovelace, Ada`
+    fn inverted(&self) -> String {
+        match self.given.is_empty() {
+            true => self.family.to_string(),
+            false => format!("{}, {}", self.family, self.given),
+        }
+    }
+
+    /// `Ada Lovelace`
+    fn natural(&self) -> String {
+        match self.given.is_empty() {
+            true => self.family.to_string(),
+            false => format!("{} {}", self.given, self.family),
+        }
+    }
+}
+
+fn names(entry: &PublicationEntry) -> Vec<Name<'_>> {
+    entry
+        .authors
+        .iter()
+        .filter(|author| !author.trim().is_empty())
+        .map(|author| Name::parse(author))
+        .collect()
+}
+
+/// `45-67` with an en dash, as the styles print ranges.
+fn page_range(pages: &str) -> String {
+    pages.replace("--", "\u{2013}").replace('-', "\u{2013}")
+}
+
+/// Appends `.` unless the text already ends a sentence.
+fn sentence(text: &str) -> String {
+    match text.ends_with(['.', '?', '!']) {
+        true => text.to_string(),
+        false => format!("{text}."),
+    }
+}
+
+fn doi_url(entry: &PublicationEntry) -> Option<String> {
+    entry
+        .doi
+        .as_ref()
+        .map(|doi| format!("https://doi.org/{doi}"))
+        .or_else(|| entry.url.clone())
+}
+
+/// `Lovelace, A., & Babbage, C. (2023). Title. Venue, 12(3), 45–67. https://doi.org/…`
+pub fn apa(entry: &PublicationEntry) -> String {
+    let authors: Vec<String> = names(entry).iter().map(Name::initialed).collect();
+    let mut parts = Vec::new();
+    let byline = match authors.as_slice() {
+        [] => None,
+        [only] => Some(only.clone()),
+        [rest @ .., last] => Some(format!("{}, & {last}", rest.join(", "))),
+    };
+    let year = format!("({}).", entry.year().unwrap_or("n.d."));
+    match byline {
+        Some(byline) => {
+            parts.push(sentence(&byline));
+            parts.push(year);
+            parts.push(sentence(&entry.title));
+        }
+        None => {
+            parts.push(sentence(&entry.title));
+            parts.push(year);
+        }
+    }
+    if let Some(venue) = &entry.venue {
+        let mut source = venue.clone();
+        if let Some(volume) = &entry.volume {
+            source.push_str(&format!(", {volume}"));
+            if let Some(issue) = &entry.issue {
+                source.push_str(&format!("({issue})"));
+            }
+        }
+        if let Some(pages) = &entry.pages {
+            source.push_str(&format!(", {}", page_range(pages)));
+        }
+        parts.push(sentence(&source));
+    }
+    parts.extend(doi_url(entry));
+    parts.join(" ")
+}
+
+/// `Lovelace, Ada, and Charles Babbage. "Title." Venue, vol. 12, no. 3, 2023, pp. 45–67. https://doi.org/….`
+pub fn mla(entry: &PublicationEntry) -> String {
+    let names = names(entry);
+    let byline = match names.as_slice() {
+        [] => None,
+        [only] => Some(only.inverted()),
+        [first, second] => Some(format!("{}, and {}", first.inverted(), second.natural())),
+        [first, ..] => Some(format!("{}, et al", first.inverted())),
+    };
+    let mut parts: Vec<String> = byline.iter().map(|byline| sentence(byline)).collect();
+    parts.push(format!("\u{201c}{}\u{201d}", sentence(&entry.title)));
+    let mut container: Vec<String> = entry.venue.iter().cloned().collect();
+    container.extend(entry.volume.iter().map(|volume| format!("vol. {volume}")));
+    container.extend(entry.issue.iter().map(|issue| format!("no. {issue}")));
+    container.extend(entry.year().map(str::to_string));
+    container.extend(entry.pages.iter().map(|pages| match pages.contains('-') {
+        true => format!("pp. {}", page_range(pages)),
+        false => format!("p. {pages}"),
+    }));
+    if !container.is_empty() {
+        parts.push(sentence(&container.join(", ")));
+    }
+    parts.extend(doi_url(entry).map(|url| sentence(&url)));
+    parts.join(" ")
+}
+
+/// `Lovelace, Ada, and Charles Babbage. "Title." Venue 12, no. 3 (2023): 45–67. https://doi.org/….`
+pub fn chicago(entry: &PublicationEntry) -> String {
+    let names = names(entry);
+    let byline = match names.as_slice() {
+        [] => None,
+        [only] => Some(only.inverted()),
+        [first, rest @ .., last] if names.len() <= 10 => {
+            let middle: String = rest
+                .iter()
+                .map(|name| format!(", {}", name.natural()))
+                .collect();
+            Some(format!(
+                "{}{middle}, and {}",
+                first.inverted(),
+                last.natural()
+            ))
+        }
+        [first, rest @ ..] => {
+            let shown: String = rest
+                .iter()
+                .take(6)
+                .map(|name| format!(", {}", name.natural()))
+                .collect();
+            Some(format!("{}{shown}, et al", first.inverted()))
+        }
+    };
+    let mut parts: Vec<String> = byline.iter().map(|byline| sentence(byline)).collect();
+    parts.push(format!("\u{201c}{}\u{201d}", sentence(&entry.title)));
+    let mut source = entry.venue.clone().unwrap_or_default();
+    if let Some(volume) = &entry.volume {
+        source.push_str(&format!(" {volume}"));
+    }
+    if let Some(issue) = &entry.issue {
+        source.push_str(&format!(", no. {issue}"));
+    }
+    if let Some(year) = entry.year() {
+        source.push_str(&format!(" ({year})"));
+    }
+    if let Some(pages) = &entry.pages {
+        source.push_str(&format!(": {}", page_range(pages)));
+    }
+    let source = source.trim();
+    if !source.is_empty() {
+        parts.push(sentence(source));
+    }
+    parts.extend(doi_url(entry).map(|url| sentence(&url)));
+    parts.join(" ")
+}
+
+/// Escapes the characters LaTeX treats specially.
+fn latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `lovelace2023analytical`, suffixed with `a`, `b`, … when two works in one
+/// export would share a key.
+fn bibtex_keys(entries: &[PublicationEntry]) -> Vec<String> {
+    let ascii = |text: &str| -> String {
+        text.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let keys: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            let author = names(entry)
+                .first()
+                .map(|name| ascii(name.family))
+                .unwrap_or_default();
+            let word = entry
+                .title
+                .split_whitespace()
+                .map(ascii)
+                .find(|word| word.len() > 3)
+                .unwrap_or_default();
+            format!("{author}{}{word}", entry.year().unwrap_or_default())
+        })
+        .map(|key| match key.is_empty() {
+            true => "work".to_string(),
+            false => key,
+        })
+        .collect();
+    keys.iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let same = keys.iter().filter(|other| *other == key).count();
+            let before = keys[..index].iter().filter(|other| *other == key).count();
+            match (same, char::from_u32('a' as u32 + before as u32)) {
+                (1, _) => key.clone(),
+                (_, Some(suffix)) if before < 26 => format!("{key}{suffix}"),
+                _ => format!("{key}{before}"),
+            }
+        })
+        .collect()
+}
+
+fn bibtex(entries: &[PublicationEntry]) -> String {
+    entries
+        .iter()
+        .zip(bibtex_keys(entries))
+        .map(|(entry, key)| {
+            let kind = Kind::of(entry);
+            let entry_type = match kind {
+                Kind::Article => "article",
+                Kind::Conference => "inproceedings",
+                Kind::Book => "book",
+                Kind::Chapter => "incollection",
+                Kind::Thesis => "phdthesis",
+                Kind::Preprint | Kind::Presentation | Kind::Other => "misc",
+            };
+            let venue_field = match kind {
+                Kind::Article => "journal",
+                Kind::Conference | Kind::Chapter => "booktitle",
+                Kind::Book => "publisher",
+                Kind::Thesis => "school",
+                Kind::Preprint | Kind::Presentation | Kind::Other => "howpublished",
+            };
+            let authors: Vec<String> = names(entry)
+                .iter()
+                .map(|name| latex(&name.inverted()))
+                .collect();
+            // Double braces keep the title's capitalisation.
+            let mut fields = vec![("title", format!("{{{}}}", latex(&entry.title)))];
+            if !authors.is_empty() {
+                fields.push(("author", authors.join(" and ")));
+            }
+            fields.extend(
+                entry
+                    .venue
+                    .as_deref()
+                    .map(|venue| (venue_field, latex(venue))),
+            );
+            fields.extend(entry.year().map(|year| ("year", year.to_string())));
+            fields.extend(
+                entry
+                    .volume
+                    .as_deref()
+                    .map(|volume| ("volume", latex(volume))),
+            );
+            fields.extend(entry.issue.as_deref().map(|issue| ("number", latex(issue))));
+            fields.extend(
+                entry
+                    .pages
+                    .as_deref()
+                    .map(|pages| ("pages", pages.replace("--", "-").replace('-', "--"))),
+            );
+            fields.extend(entry.doi.as_deref().map(|doi| ("doi", latex(doi))));
+            fields.extend(entry.url.as_deref().map(|url| ("url", url.to_string())));
+            if matches!(kind, Kind::Preprint | Kind::Presentation) {
+                fields.extend(entry.work_type.as_deref().map(|note| ("note", latex(note))));
+            }
+            let fields: Vec<String> = fields
+                .into_iter()
+                .map(|(name, value)| format!("  {name} = {{{value}}}"))
+                .collect();
+            format!("@{entry_type}{{{key},\n{}\n}}\n", fields.join(",\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn ris(entry: &PublicationEntry) -> String {
+    let entry_type = match Kind::of(entry) {
+        Kind::Article => "JOUR",
+        Kind::Conference => "CONF",
+        Kind::Book => "BOOK",
+        Kind::Chapter => "CHAP",
+        Kind::Thesis => "THES",
+        Kind::Preprint => "UNPB",
+        Kind::Presentation => "SLIDE",
+        Kind::Other => "GEN",
+    };
+    let mut lines = vec![("TY", entry_type.to_string())];
+    lines.extend(names(entry).iter().map(|name| ("AU", name.inverted())));
+    lines.push(("TI", entry.title.clone()));
+    lines.extend(entry.venue.iter().map(|venue| ("T2", venue.clone())));
+    lines.extend(entry.year().map(|year| ("PY", year.to_string())));
+    lines.extend(
+        entry
+            .published_on
+            .iter()
+            .map(|date| ("DA", format!("{}/", date.replace('-', "/")))),
+    );
+    lines.extend(entry.volume.iter().map(|volume| ("VL", volume.clone())));
+    lines.extend(entry.issue.iter().map(|issue| ("IS", issue.clone())));
+    if let Some(pages) = &entry.pages {
+        match pages.split_once('-') {
+            Some((start, end)) => {
+                lines.push(("SP", start.trim().to_string()));
+                lines.push(("EP", end.trim_start_matches('-').trim().to_string()));
+            }
+            None => lines.push(("SP", pages.clone())),
+        }
+    }
+    lines.extend(entry.doi.iter().map(|doi| ("DO", doi.clone())));
+    lines.extend(entry.url.iter().map(|url| ("UR", url.clone())));
+    lines.extend(entry.summary.iter().map(|summary| ("AB", summary.clone())));
+    lines.push(("ER", String::new()));
+    lines
+        .into_iter()
+        .map(|(tag, value)| format!("{tag}  - {value}\r\n"))
+        .collect()
+}
+
+/// One item of a CSL-JSON export, as Zotero and citeproc read it.
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct CslItem {
+    id: String,
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    title: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    author: Vec<CslName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issued: Option<CslDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    container_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+    #[serde(rename = "DOI", skip_serializing_if = "Option::is_none")]
+    doi: Option<String>,
+    #[serde(rename = "URL", skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CslName {
+    family: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    given: String,
+}
+
+#[derive(Serialize)]
+struct CslDate {
+    #[serde(rename = "date-parts")]
+    date_parts: [Vec<u32>; 1],
+}
+
+impl From<&PublicationEntry> for CslItem {
+    fn from(entry: &PublicationEntry) -> Self {
+        let item_type = match Kind::of(entry) {
+            Kind::Article => "article-journal",
+            Kind::Conference => "paper-conference",
+            Kind::Book => "book",
+            Kind::Chapter => "chapter",
+            Kind::Thesis => "thesis",
+            Kind::Preprint => "article",
+            Kind::Presentation => "speech",
+            Kind::Other => "document",
+        };
+        let issued = entry.published_on.as_deref().and_then(|date| {
+            let parts: Vec<u32> = date
+                .split('-')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            (!parts.is_empty()).then_some(CslDate {
+                date_parts: [parts],
+            })
+        });
+        Self {
+            id: entry.id.clone(),
+            item_type,
+            title: entry.title.clone(),
+            author: names(entry)
+                .iter()
+                .map(|name| CslName {
+                    family: name.family.to_string(),
+                    given: name.given.to_string(),
+                })
+                .collect(),
+            issued,
+            container_title: entry.venue.clone(),
+            volume: entry.volume.clone(),
+            issue: entry.issue.clone(),
+            page: entry.pages.clone(),
+            doi: entry.doi.clone(),
+            url: entry.url.clone(),
+            summary: entry.summary.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article() -> PublicationEntry {
+        PublicationEntry {
+            title: "Analytical engines for everyone".to_string(),
+            venue: Some("Journal of Computing".to_string()),
+            published_on: Some("2023-05-01".to_string()),
+            work_type: Some("Journal article".to_string()),
+            doi: Some("10.1000/xyz".to_string()),
+            authors: vec![
+                "Ada King Lovelace".to_string(),
+                "Babbage, Charles".to_string(),
+            ],
+            volume: Some("12".to_string()),
+            issue: Some("3".to_string()),
+            pages: Some("45-67".to_string()),
+            ..Default::default()
+        }
+    }
+
+    const AUTHORS: [&str; 11] = [
+        "Ada Lovelace",
+        "Charles Babbage",
+        "Alan Turing",
+        "Grace Hopper",
+        "Edsger Dijkstra",
+        "Barbara Liskov",
+        "Donald Knuth",
+        "Frances Allen",
+        "John Backus",
+        "Margaret Hamilton",
+        "Ken Thompson",
+    ];
+
+    fn authored(authors: usize) -> PublicationEntry {
+        PublicationEntry {
+            authors: AUTHORS[..authors]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            ..article()
+        }
+    }
+
+    #[test]
+    fn parses_names_given_names_first_or_after_a_comma() {
+        let name = Name::parse("Ada King Lovelace");
+        assert_eq!((name.given, name.family), ("Ada King", "Lovelace"));
+        let name = Name::parse(" Babbage,  Charles ");
+        assert_eq!((name.given, name.family), ("Charles", "Babbage"));
+        let name = Name::parse("Plato");
+        assert_eq!((name.given, name.family), ("", "Plato"));
+    }
+
+    #[test]
+    fn formats_names_for_each_style() {
+        assert_eq!(Name::parse("Jean-Luc Picard").initialed(), "Picard, J. L.");
+        assert_eq!(Name::parse("Plato").initialed(), "Plato");
+        assert_eq!(Name::parse("Ada Lovelace").inverted(), "Lovelace, Ada");
+        assert_eq!(Name::parse("Lovelace, Ada").natural(), "Ada Lovelace");
+    }
+
+    #[test]
+    fn prints_page_ranges_with_an_en_dash() {
+        assert_eq!(page_range("45-67"), "45\u{2013}67");
+        assert_eq!(page_range("45--67"), "45\u{2013}67");
+        assert_eq!(page_range("7"), "7");
+    }
+
+    #[test]
+    fn escapes_latex_special_characters() {
+        assert_eq!(
+            latex(r"50% of R&D_costs {x} ~ ^ \ $#"),
+            r"50\% of R\&D\_costs \{x\} \textasciitilde{} \textasciicircum{} \textbackslash{} \$\#"
+        );
+        assert_eq!(latex("Plain title"), "Plain title");
+    }
+
+    #[test]
+    fn suffixes_bibtex_keys_only_when_they_collide() {
+        let other = PublicationEntry {
+            title: "Notes on the engine".to_string(),
+            ..article()
+        };
+        let keys = bibtex_keys(&[article(), other, article(), PublicationEntry::default()]);
+        assert_eq!(
+            keys,
+            [
+                "lovelace2023analyticala",
+                "lovelace2023notes",
+                "lovelace2023analyticalb",
+                "work"
+            ]
+        );
+    }
+
+    #[test]
+    fn exports_bibtex_with_escaped_fields_and_double_dashed_pages() {
+        let entry = PublicationEntry {
+            venue: Some("Computing & Society".to_string()),
+            ..article()
+        };
+        assert_eq!(
+            CitationFormat::BibTex.export(&[entry]),
+            "@article{lovelace2023analytical,\n  \
+             title = {{Analytical engines for everyone}},\n  \
+             author = {Lovelace, Ada King and Babbage, Charles},\n  \
+             journal = {Computing \\& Society},\n  \
+             year = {2023},\n  \
+             volume = {12},\n  \
+             number = {3},\n  \
+             pages = {45--67},\n  \
+             doi = {10.1000/xyz}\n}\n"
+        );
+    }
+
+    #[test]
+    fn formats_apa() {
+        assert_eq!(
+            apa(&article()),
+            "Lovelace, A. K., & Babbage, C. (2023). Analytical engines for everyone. \
+             Journal of Computing, 12(3), 45\u{2013}67. https://doi.org/10.1000/xyz"
+        );
+        let anonymous = PublicationEntry {
+            authors: Vec::new(),
+            published_on: None,
+            venue: None,
+            doi: None,
+            ..article()
+        };
+        assert_eq!(apa(&anonymous), "Analytical engines for everyone. (n.d.).");
+    }
+
+    #[test]
+    fn formats_mla() {
+        assert_eq!(
+            mla(&article()),
+            "Lovelace, Ada King, and Charles Babbage. \
+             \u{201c}Analytical engines for everyone.\u{201d} \
+             Journal of Computing, vol. 12, no. 3, 2023, pp. 45\u{2013}67. \
+             https://doi.org/10.1000/xyz."
+        );
+        let entry = PublicationEntry {
+            pages: Some("7".to_string()),
+            ..authored(3)
+        };
+        let citation = mla(&entry);
+        assert!(citation.starts_with("Lovelace, Ada, et al. "), "{citation}");
+        assert!(citation.contains(", 2023, p. 7."), "{citation}");
+    }
+
+    #[test]
+    fn formats_chicago() {
+        assert_eq!(
+            chicago(&article()),
+            "Lovelace, Ada King, and Charles Babbage. \
+             \u{201c}Analytical engines for everyone.\u{201d} \
+             Journal of Computing 12, no. 3 (2023): 45\u{2013}67. \
+             https://doi.org/10.1000/xyz."
+        );
+        assert!(chicago(&authored(10)).starts_with(
+            "Lovelace, Ada, Charles Babbage, Alan Turing, Grace Hopper, Edsger Dijkstra, \
+             Barbara Liskov, Donald Knuth, Frances Allen, John Backus, and Margaret Hamilton. "
+        ));
+        assert!(chicago(&authored(11)).starts_with(
+            "Lovelace, Ada, Charles Babbage, Alan Turing, Grace Hopper, Edsger Dijkstra, \
+             Barbara Liskov, Donald Knuth, et al. "
+        ));
+    }
+}
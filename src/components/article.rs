@@ -1,6 +1,10 @@
+use crate::components::chart::{ACCENT, Sparkline};
 use crate::shared::*;
 use momenta::prelude::*;
 
+/// Amber-400, matching the star icon's usual colour.
+const STAR_COLOR: &str = "#fbbf24";
+
 pub struct ArticleProps {
     pub post: Post,
     pub show_read_more: bool,
@@ -26,7 +30,7 @@ pub fn Article(
                     </span>
                     {when!(let Some(published_at) = &post.published_at =>
                         <time
-                            datetime={&*published_at}
+                            datetime={published_at}
                             class="text-xs text-zinc-500"
                         >
                             <span>
@@ -57,17 +61,35 @@ pub fn Article(
 
 #[component]
 pub fn ProjectArticle(project: &Project) -> Node {
-    let brief = project
-        .description
-        .is_empty()
-        .then(|| &project.name)
-        .unwrap_or(&project.description);
+    let brief = if project.description.is_empty() {
+        &project.name
+    } else {
+        &project.description
+    };
+    let activity = &project.activity;
+    let commit_weeks = activity.commits.len();
+    let star_weeks = activity.stars.len();
     rsx! {
         <article class="group flex flex-col h-full card-item rounded-lg p-5 transition-all">
             <div class="flex flex-col gap-4 flex-1">
-                <h2 class="text-xl md:text-2xl font-semibold text-zinc-100 group-hover:text-white">
-                    {&project.name}
-                </h2>
+                <a href={format!("/projects/{}", project.name)} tabindex={-1} aria_hidden={true}>
+                    <img
+                        src={project.cover()}
+                        alt=""
+                        loading="lazy"
+                        class="w-full aspect-[2/1] object-contain rounded-md border border-zinc-800 bg-zinc-900"
+                    />
+                </a>
+                <div class="flex items-start justify-between gap-2">
+                    <h2 class="text-xl md:text-2xl font-semibold text-zinc-100 group-hover:text-white">
+                        <a href={format!("/projects/{}", project.name)}>{&project.name}</a>
+                    </h2>
+                    {when!(project.pinned =>
+                        <span class="inline-flex items-center px-2 py-0.5 text-[11px] uppercase tracking-[0.08em] font-semibold rounded-md" style="background: var(--accent-dim); border: 1px solid var(--accent-border); color: var(--accent);">
+                            <i class="fas fa-thumbtack mr-1"></i>"pinned"
+                        </span>
+                    )}
+                </div>
                 <p class="text-sm md:text-base text-zinc-400 leading-relaxed">
                     {&brief[0..(brief.len().min(120))]}...
                 </p>
@@ -82,16 +104,78 @@ pub fn ProjectArticle(project: &Project) -> Node {
                 } else {
                     rsx! { <></> }
                 }}
+                {if !project.languages.is_empty() {
+                    rsx! {
+                        <div class="space-y-2">
+                            <div class="flex h-1.5 rounded-full overflow-hidden bg-zinc-800">
+                                {project.languages.iter().map(|language| rsx! {
+                                    <span
+                                        title={format!("{} {:.1}%", language.name, language.percent)}
+                                        style={format!("width: {:.1}%; background: {};", language.percent, language_color(language))}
+                                    ></span>
+                                })}
+                            </div>
+                            <div class="flex flex-wrap gap-x-3 gap-y-1 text-xs text-zinc-500">
+                                {project.languages.iter().take(3).map(|language| rsx! {
+                                    <span class="inline-flex items-center gap-1">
+                                        <span class="w-2 h-2 rounded-full" style={format!("background: {};", language_color(language))}></span>
+                                        {format!("{} {:.0}%", language.name, language.percent)}
+                                    </span>
+                                })}
+                            </div>
+                        </div>
+                    }
+                } else {
+                    rsx! { <></> }
+                }}
+                {when!(commit_weeks > 1 || star_weeks > 1 =>
+                    <div class="grid grid-cols-2 gap-3 text-xs text-zinc-500">
+                        {when!(commit_weeks > 1 =>
+                            <div class="flex flex-col gap-1">
+                                <Sparkline
+                                    title={format!("Weekly commits over the last {commit_weeks} weeks")}
+                                    points={activity.commits.clone()}
+                                    color={ACCENT}
+                                />
+                                <span>{format!("{} commits in {RECENT_WEEKS} weeks", activity.recent_commits())}</span>
+                            </div>
+                        )}
+                        {when!(star_weeks > 1 =>
+                            <div class="flex flex-col gap-1">
+                                <Sparkline
+                                    title={format!("Stars over the last {star_weeks} weeks")}
+                                    points={activity.stars.clone()}
+                                    color={STAR_COLOR}
+                                />
+                                <span>{format!("+{} stars in {star_weeks} weeks", activity.stars_gained())}</span>
+                            </div>
+                        )}
+                    </div>
+                )}
                 <div class="flex items-center justify-between flex-wrap gap-2 mt-auto">
-                    <span class="flex items-center gap-3 text-xs text-zinc-500">
-                        <span><i class="fas fa-star mr-1"></i>{project.stargazers_count}</span>
-                        {when!(let Some(lang) = &project.language =>
-                            <span><i class="fas fa-code mr-1"></i>{lang}</span>
+                    <span class="flex items-center flex-wrap gap-3 text-xs text-zinc-500">
+                        <span title="Stars"><i class="fas fa-star mr-1"></i>{project.stargazers_count}</span>
+                        {when!(project.languages.is_empty() =>
+                            {when!(let Some(lang) = &project.language =>
+                                <span title="Language"><i class="fas fa-code mr-1"></i>{lang}</span>
+                            )}
+                        )}
+                        {when!(let Some(license) = &project.license =>
+                            <span title="License"><i class="fas fa-scale-balanced mr-1"></i>{license}</span>
+                        )}
+                        <span title="Open issues"><i class="fas fa-circle-dot mr-1"></i>{project.open_issues}</span>
+                        {when!(let Some(releases) = project.releases.filter(|releases| *releases > 0) =>
+                            <span title="Releases"><i class="fas fa-tag mr-1"></i>{releases}</span>
                         )}
                     </span>
-                    <a href={&project.url} class="text-sm" style="color: var(--accent);">"view project"</a>
+                    <a href={format!("/projects/{}", project.name)} class="text-sm" style="color: var(--accent);">"view project"</a>
                 </div>
             </div>
         </article>
     }
 }
+
+/// Languages GitHub has no colour for are drawn in neutral grey.
+fn language_color(language: &LanguageShare) -> &str {
+    language.color.as_deref().unwrap_or("#71717a")
+}
@@ -0,0 +1,182 @@
+use crate::shared::svg_elements;
+use momenta::prelude::*;
+
+const LABEL_WIDTH: f32 = 150.0;
+const VALUE_WIDTH: f32 = 90.0;
+const CHART_WIDTH: f32 = 640.0;
+const ROW_HEIGHT: f32 = 28.0;
+const BAR_HEIGHT: f32 = 12.0;
+const SPARKLINE_WIDTH: f32 = 96.0;
+const SPARKLINE_HEIGHT: f32 = 24.0;
+/// Room above and below the line for the stroke and end marker.
+const SPARKLINE_INSET: f32 = 3.0;
+/// The site accent, `--accent` in `global.css`; presentation attributes
+/// can't read CSS variables.
+pub const ACCENT: &str = "#ff5f1f";
+/// Zinc-500, for series without a colour of their own.
+pub const NEUTRAL: &str = "#71717a";
+
+pub struct Bar {
+    pub label: String,
+    pub value: f32,
+    /// Text shown at the end of the bar, e.g. `42%`.
+    pub display: String,
+    pub color: String,
+    /// Hover text for the bar.
+    pub tooltip: String,
+}
+
+/// Horizontal bars scaled against the largest value, one labelled row each.
+pub struct BarChartProps {
+    pub title: String,
+    pub bars: Vec<Bar>,
+}
+
+#[component]
+pub fn BarChart(BarChartProps { title, bars }: &BarChartProps) -> Node {
+    use svg_elements as momenta;
+
+    let max = bars.iter().map(|bar| bar.value).fold(0.0, f32::max);
+    let track = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    let height = ROW_HEIGHT * bars.len() as f32;
+    // (bar, text baseline, bar top, bar width) for each row.
+    let rows: Vec<(&Bar, f32, f32, f32)> = bars
+        .iter()
+        .enumerate()
+        .map(|(row, bar)| {
+            let top = row as f32 * ROW_HEIGHT;
+            let width = match max > 0.0 {
+                true => (bar.value / max * track).max(2.0),
+                false => 0.0,
+            };
+            (
+                bar,
+                top + ROW_HEIGHT / 2.0,
+                top + (ROW_HEIGHT - BAR_HEIGHT) / 2.0,
+                width,
+            )
+        })
+        .collect();
+    rsx! {
+        <svg viewBox={format!("0 0 {CHART_WIDTH} {height}")} class="w-full h-auto" role="img" aria_label={title}>
+            <title>{title}</title>
+            {rows.iter().map(|(bar, middle, top, width)| {
+                <g>
+                    <title>{&bar.tooltip}</title>
+                    <text x="0" y={format!("{middle:.1}")} fill="#d4d4d8" font_size="13" dominant_baseline="middle">
+                        {&bar.label}
+                    </text>
+                    <path d={bar_path(LABEL_WIDTH, *top, track, BAR_HEIGHT)} fill="#27272a" />
+                    <path d={bar_path(LABEL_WIDTH, *top, *width, BAR_HEIGHT)} fill={&bar.color} />
+                    <text x={format!("{CHART_WIDTH}")} y={format!("{middle:.1}")} fill="#a1a1aa" font_size="12" text_anchor="end" dominant_baseline="middle">
+                        {&bar.display}
+                    </text>
+                </g>
+            })}
+        </svg>
+    }
+}
+
+/// A ring split into segments by percentage. Each segment is a circle whose
+/// dashed stroke covers its share; the circumference is 100 so dash lengths
+/// are percentages.
+pub struct DonutChartProps {
+    pub title: String,
+    /// Segments as `(label, percent, colour)`; percents should sum to 100.
+    pub segments: Vec<(String, f32, String)>,
+}
+
+#[component]
+pub fn DonutChart(DonutChartProps { title, segments }: &DonutChartProps) -> Node {
+    use svg_elements as momenta;
+
+    let radius = 100.0 / (2.0 * std::f32::consts::PI);
+    // (label, percent, colour, dash offset): each segment starts where the
+    // previous one ended.
+    let mut start = 0.0;
+    let arcs: Vec<(&String, f32, &String, f32)> = segments
+        .iter()
+        .map(|(label, percent, color)| {
+            let arc = (label, *percent, color, -start);
+            start += percent;
+            arc
+        })
+        .collect();
+    rsx! {
+        <svg viewBox="0 0 42 42" class="w-full h-auto" role="img" aria_label={title}>
+            <title>{title}</title>
+            <circle cx="21" cy="21" r={format!("{radius:.4}")} fill="none" stroke="#27272a" stroke_width="6" />
+            {arcs.iter().map(|(label, percent, color, dash_offset)| {
+                <circle
+                    cx="21"
+                    cy="21"
+                    r={format!("{radius:.4}")}
+                    fill="none"
+                    stroke={*color}
+                    stroke_width="6"
+                    stroke_dasharray={format!("{percent:.2} {:.2}", 100.0 - percent)}
+                    stroke_dashoffset={format!("{dash_offset:.2}")}
+                    transform="rotate(-90 21 21)"
+                >
+                    <title>{format!("{label} {percent:.1}%")}</title>
+                </circle>
+            })}
+        </svg>
+    }
+}
+
+/// A small line chart of a series, scaled to its own range, for reading a
+/// trend at a glance beside a figure. Needs at least two points.
+pub struct SparklineProps {
+    pub title: String,
+    pub points: Vec<u32>,
+    pub color: String,
+}
+
+#[component]
+pub fn Sparkline(
+    SparklineProps {
+        title,
+        points,
+        color,
+    }: &SparklineProps,
+) -> Node {
+    use svg_elements as momenta;
+
+    let low = points.iter().copied().min().unwrap_or(0) as f32;
+    let high = points.iter().copied().max().unwrap_or(0) as f32;
+    let step = SPARKLINE_WIDTH / points.len().saturating_sub(1).max(1) as f32;
+    let plot = SPARKLINE_HEIGHT - 2.0 * SPARKLINE_INSET;
+    // A flat series sits on the baseline.
+    let coordinates: Vec<(f32, f32)> = points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            let share = match high > low {
+                true => (*point as f32 - low) / (high - low),
+                false => 0.0,
+            };
+            (
+                index as f32 * step,
+                SPARKLINE_HEIGHT - SPARKLINE_INSET - share * plot,
+            )
+        })
+        .collect();
+    let line = coordinates
+        .iter()
+        .map(|(x, y)| format!("{x:.1},{y:.1}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (end_x, end_y) = coordinates.last().copied().unwrap_or_default();
+    rsx! {
+        <svg viewBox={format!("-2 0 {} {SPARKLINE_HEIGHT}", SPARKLINE_WIDTH + 4.0)} class="w-[100px] h-6 shrink-0" role="img" aria_label={title}>
+            <title>{title}</title>
+            <polyline points={line} fill="none" stroke={color} stroke_width="1.5" stroke_linecap="round" stroke_linejoin="round" />
+            <circle cx={format!("{end_x:.1}")} cy={format!("{end_y:.1}")} r="2" fill={color} />
+        </svg>
+    }
+}
+
+fn bar_path(x: f32, y: f32, width: f32, height: f32) -> String {
+    format!("M{x:.1},{y:.1}h{width:.1}v{height:.1}h{:.1}z", -width)
+}
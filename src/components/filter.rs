@@ -0,0 +1,92 @@
+use crate::shared::escape_xml;
+use momenta::prelude::*;
+
+/// A listing filtered by datastar signals that double as its URL parameters,
+/// e.g. `{"tag": "rust", "q": ""}` for `?tag=rust`.
+pub struct Filters {
+    pub signals: serde_json::Value,
+    /// Streams the first page for new signals when sent an empty cursor.
+    pub endpoint: &'static str,
+    /// The listing's shareable URL for a set of signals.
+    pub href: fn(&serde_json::Value) -> String,
+}
+
+impl Filters {
+    /// A link that sets `signal` to `value`, an empty value clearing it.
+    pub fn pill(&self, signal: &str, value: &str, label: String) -> FilterPill {
+        let mut params = self.signals.clone();
+        let current = params[signal] == value;
+        params[signal] = value.into();
+        let value = serde_json::Value::from(value);
+        // The renderer writes attribute values as they are.
+        FilterPill {
+            href: escape_xml(&(self.href)(&params)),
+            label,
+            on_click: escape_xml(&format!(
+                "evt.preventDefault(); ${signal} = {value}; $cursor = ''; @get('{}')",
+                self.endpoint
+            )),
+            current_when: escape_xml(&format!("${signal} == {value}")),
+            current: if current { "true" } else { "false" },
+        }
+    }
+
+    /// A link that switches the boolean `signal` on or off, current while
+    /// it is on.
+    pub fn toggle(&self, signal: &str, label: String) -> FilterPill {
+        let mut params = self.signals.clone();
+        let on = params[signal] == true;
+        params[signal] = (!on).into();
+        FilterPill {
+            href: escape_xml(&(self.href)(&params)),
+            label,
+            on_click: escape_xml(&format!(
+                "evt.preventDefault(); ${signal} = !${signal}; $cursor = ''; @get('{}')",
+                self.endpoint
+            )),
+            current_when: escape_xml(&format!("${signal}")),
+            current: if on { "true" } else { "false" },
+        }
+    }
+
+    /// The signals as a `data-signals` value.
+    pub fn data_signals(&self) -> String {
+        escape_xml(&self.signals.to_string())
+    }
+
+    /// Submits a search form through the endpoint instead of reloading.
+    pub fn on_submit(&self) -> String {
+        format!(
+            "evt.preventDefault(); $cursor = ''; @get('{}')",
+            self.endpoint
+        )
+    }
+}
+
+/// A filter link: a shareable URL without scripts, and with them a signal
+/// change that streams in the filtered listing.
+pub struct FilterPill {
+    href: String,
+    label: String,
+    on_click: String,
+    /// Keeps `aria-current` in step with the signal once datastar takes over.
+    current_when: String,
+    current: &'static str,
+}
+
+/// A row of filter links, hidden when there is nothing to choose between.
+pub struct FilterNavProps {
+    pub label: &'static str,
+    pub pills: Vec<FilterPill>,
+}
+
+#[component]
+pub fn FilterNav(FilterNavProps { label, pills }: &FilterNavProps) -> Node {
+    rsx! {
+        <nav class="flex flex-wrap gap-2 text-xs" aria_label={*label} hidden={pills.len() < 2}>
+            {pills.iter().map(|pill| {
+                <a href={pill.href.as_str()} class="filter-pill px-3 py-1.5 rounded-full border border-zinc-700 text-zinc-500 hover:border-zinc-500 transition-colors" aria_current={pill.current} data_attr_aria_current={pill.current_when.as_str()} data_on_click={pill.on_click.as_str()}>{pill.label.as_str()}</a>
+            })}
+        </nav>
+    }
+}
@@ -1,4 +1,5 @@
-use crate::shared::NAVIGATION;
+use crate::app::routes::navigation;
+use crate::shared::escape_xml;
 use lazy_static::lazy_static;
 use momenta::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -32,7 +33,7 @@ struct OpenGraph {
     type_: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct OpenGraphImage {
     url: String,
     width: u32,
@@ -117,35 +118,67 @@ lazy_static! {
 #[derive(Default)]
 pub struct LayoutProps {
     pub title: String,
+    /// Share card for the page; falls back to the site-wide `/og.png`.
+    pub og_image: Option<String>,
+    /// Summary for search results and share previews; falls back to the
+    /// site-wide description.
+    pub description: Option<String>,
+    /// Canonical path of the page, e.g. `/projects/crate`.
+    pub canonical: Option<String>,
+    /// Extra `<meta name content>` tags for the page, such as the Highwire
+    /// Press `citation_*` tags Google Scholar reads.
+    pub meta: Vec<(&'static str, String)>,
     pub children: Vec<Node>,
 }
 
 #[component]
 pub fn AppLayout(props: &LayoutProps) -> Node {
     let page_title = METADATA.title.template.replace("%s", &props.title);
+    let og_image = match &props.og_image {
+        Some(url) => OpenGraphImage {
+            url: url.clone(),
+            width: 1200,
+            height: 630,
+        },
+        None => METADATA.open_graph.images[0].clone(),
+    };
+    // momenta escapes text nodes but not attribute values, so everything that
+    // lands in a `content` or `href` below is escaped here, once.
+    let title = escape_xml(&page_title);
+    let description = escape_xml(
+        props
+            .description
+            .as_deref()
+            .unwrap_or(&METADATA.description),
+    );
+    let image = escape_xml(&og_image.url);
+    let url = escape_xml(&match &props.canonical {
+        Some(path) => format!("{}{path}", METADATA.metadata_base),
+        None => METADATA.open_graph.url.clone(),
+    });
     rsx! {
         <html lang="en-US" class="scroll-smooth" style="font-family: 'DM Sans', sans-serif;">
             <head>
                 <title>{&page_title}</title>
                 <meta charset="utf-8" />
-                <meta name="title" content={&page_title} />
-                <meta name="description" content={&METADATA.description} />
+                <meta name="title" content={title.as_str()} />
+                <meta name="description" content={description.as_str()} />
                 <meta name="viewport" content="width=device-width, initial-scale=1" />
 
-                <meta property="og:title" content={&METADATA.open_graph.title} />
-                <meta property="og:description" content={&METADATA.open_graph.description} />
-                <meta property="og:url" content={METADATA.open_graph.url.as_str()} />
+                <meta property="og:title" content={title.as_str()} />
+                <meta property="og:description" content={description.as_str()} />
+                <meta property="og:url" content={url.as_str()} />
                 <meta property="og:site_name" content={&METADATA.open_graph.site_name} />
-                <meta property="og:image" content={METADATA.open_graph.images[0].url.as_str()} />
-                <meta property="og:image:width" content={METADATA.open_graph.images[0].width.to_string()} />
-                <meta property="og:image:height" content={METADATA.open_graph.images[0].height.to_string()} />
+                <meta property="og:image" content={image.as_str()} />
+                <meta property="og:image:width" content={og_image.width.to_string()} />
+                <meta property="og:image:height" content={og_image.height.to_string()} />
                 <meta property="og:locale" content={&METADATA.open_graph.locale} />
                 <meta property="og:type" content={&METADATA.open_graph.type_} />
 
-                <meta name="twitter:title" content={&METADATA.twitter.title} />
+                <meta name="twitter:title" content={title.as_str()} />
                 <meta name="twitter:creator" content={&METADATA.twitter.creator} />
                 <meta name="twitter:card" content={&METADATA.twitter.card} />
-                <meta name="twitter:image" content={METADATA.open_graph.images[0].url.as_str()} />
+                <meta name="twitter:image" content={image.as_str()} />
 
                 <meta name="robots" content={
                     format!(
@@ -154,9 +187,18 @@ pub fn AppLayout(props: &LayoutProps) -> Node {
                         if METADATA.robots.follow { ", follow" } else { ", nofollow" }
                     )
                 } />
+                {props.meta.iter().map(|(name, content)| {
+                    <meta name={*name} content={escape_xml(content)} />
+                })}
+                {when!(props.canonical.is_some() =>
+                    <link rel="canonical" href={url.as_str()} />
+                )}
                 <link rel="icon" href={&METADATA.icons.shortcut} />
                 <link rel="shortcut icon" href={&METADATA.icons.shortcut} />
                 <link rel="apple-touch-icon" href={&METADATA.icons.shortcut} />
+                <link rel="alternate" type="application/rss+xml" title="elcharitas.wtf — Essays" href="/rss.xml" />
+                <link rel="alternate" type="application/atom+xml" title="elcharitas.wtf — Essays" href="/atom.xml" />
+                <link rel="alternate" type="application/feed+json" title="elcharitas.wtf — Essays" href="/feed.json" />
                 <link rel="stylesheet" href="/styles.css" />
                 <link rel="stylesheet" href="https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.7.2/css/all.min.css" />
 
@@ -220,8 +262,8 @@ pub fn Navigation() -> Node {
                 </a>
 
                 <div class="hidden md:flex items-center gap-5">
-                    {NAVIGATION.iter().map(|nav| {
-                        <a href={nav.href} class="nav-link text-sm" data_nav_href={nav.href}>{nav.name}</a>
+                    {navigation().map(|nav| {
+                        <a href={nav.path} class="nav-link text-sm" data_nav_href={nav.path}>{nav.label.unwrap_or_default()}</a>
                     })}
                     <a href="/newsletter" class="btn-accent text-sm rounded-md px-3 py-1.5">"Newsletter"</a>
                 </div>
@@ -234,8 +276,8 @@ pub fn Navigation() -> Node {
 
             <div data_show="$mobileMenu" class="menu-panel md:hidden border-t border-zinc-800 bg-black/95 px-4 pb-4">
                 <div class="pt-3 space-y-2">
-                    {NAVIGATION.iter().map(|nav| {
-                        <a href={nav.href} class="block px-3 py-2 text-zinc-300 border border-zinc-800 rounded-lg hover:bg-zinc-900" data_mobile_nav_href={nav.href}>{nav.name}</a>
+                    {navigation().map(|nav| {
+                        <a href={nav.path} class="block px-3 py-2 text-zinc-300 border border-zinc-800 rounded-lg hover:bg-zinc-900" data_mobile_nav_href={nav.path}>{nav.label.unwrap_or_default()}</a>
                     })}
                     <a href="/newsletter" class="block px-3 py-2 text-zinc-200 border border-zinc-700 rounded-lg hover:bg-zinc-900">"Newsletter"</a>
                 </div>
@@ -247,7 +289,13 @@ pub fn Navigation() -> Node {
 #[component]
 pub fn PageLayout(props: &LayoutProps) -> Node {
     rsx! {
-        <AppLayout title={&props.title}>
+        <AppLayout
+            title={&props.title}
+            og_image={props.og_image.clone()}
+            description={props.description.clone()}
+            canonical={props.canonical.clone()}
+            meta={props.meta.clone()}
+        >
             <div class="relative min-h-screen bg-[#0a0a0a]">
                 <div class="relative">
                     <Navigation />
@@ -1,5 +1,8 @@
 pub mod article;
 pub mod card;
+pub mod chart;
+pub mod filter;
 pub mod layout;
+pub mod structured_data;
 
 pub use layout::PageLayout;
@@ -0,0 +1,233 @@
+use crate::shared::{BASE_URL, Post, Project};
+use momenta::prelude::*;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Person {
+    pub name: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub same_as: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub knows_about: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alumni_of: Vec<Organization>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Organization {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Periodical {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BlogPosting {
+    pub headline: String,
+    pub description: String,
+    pub url: String,
+    pub main_entity_of_page: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_modified: Option<String>,
+    pub image: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_count: Option<usize>,
+    pub time_required: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article_section: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    pub author: Person,
+}
+
+impl BlogPosting {
+    pub fn new(post: &Post, author: Person) -> Self {
+        let url = format!("{BASE_URL}/essays/{}", post.slug);
+        let image = post
+            .cover_image
+            .as_ref()
+            .and_then(|cover| cover.url.clone())
+            .unwrap_or_else(|| format!("{BASE_URL}/og.png"));
+
+        Self {
+            headline: post.title.clone(),
+            description: post.brief.clone(),
+            main_entity_of_page: url.clone(),
+            url,
+            date_published: post.published_at.clone(),
+            date_modified: post
+                .updated_at
+                .clone()
+                .or_else(|| post.published_at.clone()),
+            image,
+            word_count: post
+                .content
+                .as_ref()
+                .map(|content| content.markdown.split_whitespace().count()),
+            time_required: format!("PT{}M", post.read_time_in_minutes),
+            article_section: post.tags.first().map(|tag| tag.name.clone()),
+            keywords: post.tags.iter().map(|tag| tag.name.clone()).collect(),
+            author,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct ScholarlyArticle {
+    pub headline: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_part_of: Option<Periodical>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct SoftwareSourceCode {
+    pub name: String,
+    pub description: String,
+    pub code_repository: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub programming_language: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    pub date_modified: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    pub author: Person,
+}
+
+impl SoftwareSourceCode {
+    pub fn new(project: &Project, author: Person) -> Self {
+        Self {
+            name: project.name.clone(),
+            description: project.description.clone(),
+            code_repository: project.url.clone(),
+            url: if project.homepage.is_empty() {
+                project.url.clone()
+            } else {
+                project.homepage.clone()
+            },
+            programming_language: project.language.clone(),
+            keywords: project.tags.clone(),
+            date_modified: project.updated_at.clone(),
+            image: Some(project.cover()),
+            license: project
+                .license
+                .as_ref()
+                .map(|spdx| format!("https://spdx.org/licenses/{spdx}.html")),
+            author,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BreadcrumbList {
+    pub item_list_element: Vec<ListItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct ListItem {
+    pub position: usize,
+    pub name: String,
+    pub item: String,
+}
+
+impl BreadcrumbList {
+    /// Builds a trail from `(name, path)` pairs, rooted at the home page.
+    pub fn new(crumbs: &[(&str, &str)]) -> Self {
+        let item_list_element = std::iter::once(("Home", "/"))
+            .chain(crumbs.iter().copied())
+            .enumerate()
+            .map(|(index, (name, path))| ListItem {
+                position: index + 1,
+                name: name.to_string(),
+                item: format!("{BASE_URL}{path}"),
+            })
+            .collect();
+        Self { item_list_element }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum StructuredData {
+    Person(Person),
+    BlogPosting(BlogPosting),
+    ScholarlyArticle(ScholarlyArticle),
+    SoftwareSourceCode(SoftwareSourceCode),
+    BreadcrumbList(BreadcrumbList),
+}
+
+impl From<Person> for StructuredData {
+    fn from(value: Person) -> Self {
+        StructuredData::Person(value)
+    }
+}
+
+impl From<BlogPosting> for StructuredData {
+    fn from(value: BlogPosting) -> Self {
+        StructuredData::BlogPosting(value)
+    }
+}
+
+impl From<ScholarlyArticle> for StructuredData {
+    fn from(value: ScholarlyArticle) -> Self {
+        StructuredData::ScholarlyArticle(value)
+    }
+}
+
+impl From<SoftwareSourceCode> for StructuredData {
+    fn from(value: SoftwareSourceCode) -> Self {
+        StructuredData::SoftwareSourceCode(value)
+    }
+}
+
+impl From<BreadcrumbList> for StructuredData {
+    fn from(value: BreadcrumbList) -> Self {
+        StructuredData::BreadcrumbList(value)
+    }
+}
+
+pub struct JsonLdProps {
+    pub data: Vec<StructuredData>,
+}
+
+#[component]
+pub fn JsonLd(JsonLdProps { data }: &JsonLdProps) -> Node {
+    let graph = serde_json::json!({
+        "@context": "https://schema.org",
+        "@graph": data,
+    });
+    // `</script>` inside a string value would otherwise close the tag early.
+    let json = graph.to_string().replace("</", "<\\/");
+    rsx! {
+        <script type="application/ld+json" _dangerously_set_inner_html={json} />
+    }
+}
@@ -0,0 +1,341 @@
+//! Server-sent events for [datastar](https://data-star.dev) requests.
+//!
+//! Handlers answer `@get` requests with an [`Sse`] of typed events instead of
+//! hand-written `event:`/`data:` lines. Each event is framed here, one
+//! `data:` line per line of its payload, and sent as its own chunk so the
+//! browser can apply it as soon as it arrives.
+
+use axum::body::{Body, Bytes};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use std::convert::Infallible;
+use std::fmt::{self, Display, Write};
+
+/// How merged fragments land relative to their target. Every mode datastar
+/// accepts is here, including those no handler picks yet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(not(test), allow(dead_code))]
+pub enum MergeMode {
+    /// Morphs the target into the fragment, keeping unchanged nodes.
+    #[default]
+    Morph,
+    /// Replaces the target's children with the fragment.
+    Inner,
+    /// Replaces the target with the fragment.
+    Outer,
+    /// Adds the fragment as the target's first child.
+    Prepend,
+    /// Adds the fragment as the target's last child.
+    Append,
+    /// Inserts the fragment just before the target.
+    Before,
+    /// Inserts the fragment just after the target.
+    After,
+}
+
+impl MergeMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Morph => "morph",
+            Self::Inner => "inner",
+            Self::Outer => "outer",
+            Self::Prepend => "prepend",
+            Self::Append => "append",
+            Self::Before => "before",
+            Self::After => "after",
+        }
+    }
+}
+
+/// Merges HTML into the page, by default into the elements whose ids match
+/// the fragment's top-level elements.
+pub struct MergeFragments {
+    fragments: String,
+    selector: Option<String>,
+    mode: MergeMode,
+}
+
+impl MergeFragments {
+    pub fn new(fragments: impl Display) -> Self {
+        Self {
+            fragments: fragments.to_string(),
+            selector: None,
+            mode: MergeMode::default(),
+        }
+    }
+
+    pub fn selector(mut self, selector: &str) -> Self {
+        self.selector = Some(selector.to_string());
+        self
+    }
+
+    pub fn mode(mut self, mode: MergeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Removes every element matching a CSS selector.
+pub struct RemoveFragments {
+    selector: String,
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+impl RemoveFragments {
+    pub fn new(selector: &str) -> Self {
+        Self {
+            selector: selector.to_string(),
+        }
+    }
+}
+
+/// Updates signals from a JSON object, e.g. `json!({"cursor": "2"})`.
+pub struct MergeSignals {
+    signals: serde_json::Value,
+}
+
+impl MergeSignals {
+    pub fn new(signals: serde_json::Value) -> Self {
+        Self { signals }
+    }
+}
+
+/// Runs JavaScript on the page through a `<script>` that removes itself
+/// afterwards.
+pub struct ExecuteScript {
+    script: String,
+}
+
+impl ExecuteScript {
+    pub fn new(script: impl Display) -> Self {
+        Self {
+            script: script.to_string(),
+        }
+    }
+}
+
+pub enum Event {
+    MergeFragments(MergeFragments),
+    #[cfg_attr(not(test), allow(dead_code))]
+    RemoveFragments(RemoveFragments),
+    MergeSignals(MergeSignals),
+    ExecuteScript(ExecuteScript),
+}
+
+impl From<MergeFragments> for Event {
+    fn from(event: MergeFragments) -> Self {
+        Self::MergeFragments(event)
+    }
+}
+
+impl From<RemoveFragments> for Event {
+    fn from(event: RemoveFragments) -> Self {
+        Self::RemoveFragments(event)
+    }
+}
+
+impl From<MergeSignals> for Event {
+    fn from(event: MergeSignals) -> Self {
+        Self::MergeSignals(event)
+    }
+}
+
+impl From<ExecuteScript> for Event {
+    fn from(event: ExecuteScript) -> Self {
+        Self::ExecuteScript(event)
+    }
+}
+
+/// Writes `data: {name} {line}` for every line of `value`, since a bare
+/// line break would end the field. Like the event stream itself, this takes
+/// `\r\n`, `\r` and `\n` as line breaks, and ignores one at the very end.
+fn data_lines(f: &mut fmt::Formatter<'_>, name: &str, value: &str) -> fmt::Result {
+    let value = value
+        .strip_suffix("\r\n")
+        .or_else(|| value.strip_suffix(['\r', '\n']))
+        .unwrap_or(value);
+    if value.is_empty() {
+        return Ok(());
+    }
+    for line in value
+        .split("\r\n")
+        .flat_map(|line| line.split(['\r', '\n']))
+    {
+        writeln!(f, "data: {name} {line}")?;
+    }
+    Ok(())
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MergeFragments(event) => {
+                writeln!(f, "event: datastar-merge-fragments")?;
+                if let Some(selector) = &event.selector {
+                    writeln!(f, "data: selector {selector}")?;
+                }
+                if event.mode != MergeMode::default() {
+                    writeln!(f, "data: mergeMode {}", event.mode.as_str())?;
+                }
+                data_lines(f, "fragments", &event.fragments)?;
+            }
+            Self::RemoveFragments(event) => {
+                writeln!(f, "event: datastar-remove-fragments")?;
+                writeln!(f, "data: selector {}", event.selector)?;
+            }
+            Self::MergeSignals(event) => {
+                writeln!(f, "event: datastar-merge-signals")?;
+                writeln!(f, "data: signals {}", event.signals)?;
+            }
+            Self::ExecuteScript(event) => {
+                writeln!(f, "event: datastar-execute-script")?;
+                data_lines(f, "script", &event.script)?;
+            }
+        }
+        f.write_char('\n')
+    }
+}
+
+/// A `text/event-stream` response that writes each event as `events`
+/// yields it; empty when there is nothing to change.
+pub struct Sse {
+    events: BoxStream<'static, Event>,
+}
+
+impl Sse {
+    pub fn new(events: impl Stream<Item = Event> + Send + 'static) -> Self {
+        Self {
+            events: events.boxed(),
+        }
+    }
+}
+
+impl Default for Sse {
+    fn default() -> Self {
+        Self::new(stream::empty())
+    }
+}
+
+impl<E: Into<Event>> FromIterator<E> for Sse {
+    fn from_iter<I: IntoIterator<Item = E>>(events: I) -> Self {
+        let events: Vec<Event> = events.into_iter().map(Into::into).collect();
+        Self::new(stream::iter(events))
+    }
+}
+
+impl IntoResponse for Sse {
+    fn into_response(self) -> Response {
+        let chunks = self
+            .events
+            .map(|event| Ok::<_, Infallible>(Bytes::from(event.to_string())));
+        (
+            [
+                (header::CONTENT_TYPE, "text/event-stream"),
+                (header::CACHE_CONTROL, "no-cache"),
+            ],
+            Body::from_stream(chunks),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_each_line_of_a_fragment_as_its_own_data_line() {
+        let event = Event::from(MergeFragments::new("<ul>\n<li>one</li>\n</ul>"));
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-merge-fragments\n\
+             data: fragments <ul>\n\
+             data: fragments <li>one</li>\n\
+             data: fragments </ul>\n\n"
+        );
+    }
+
+    #[test]
+    fn writes_selector_and_mode_before_the_fragments() {
+        let event = Event::from(
+            MergeFragments::new("<div id=\"rows\"></div>")
+                .selector("#rows")
+                .mode(MergeMode::Append),
+        );
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-merge-fragments\n\
+             data: selector #rows\n\
+             data: mergeMode append\n\
+             data: fragments <div id=\"rows\"></div>\n\n"
+        );
+    }
+
+    #[test]
+    fn leaves_the_default_mode_out() {
+        let event = Event::from(MergeFragments::new("<p></p>").mode(MergeMode::Morph));
+        assert!(!event.to_string().contains("mergeMode"));
+    }
+
+    #[test]
+    fn frames_a_multi_line_script_without_blank_data_lines_ending_it() {
+        let event = Event::from(ExecuteScript::new("let a = 1;\r\nconsole.log(a);\n"));
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-execute-script\n\
+             data: script let a = 1;\n\
+             data: script console.log(a);\n\n"
+        );
+    }
+
+    #[test]
+    fn splits_data_lines_on_a_bare_carriage_return() {
+        let event = Event::from(MergeFragments::new("<ul>\r<li>one</li>\r\n</ul>\r"));
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-merge-fragments\n\
+             data: fragments <ul>\n\
+             data: fragments <li>one</li>\n\
+             data: fragments </ul>\n\n"
+        );
+    }
+
+    #[test]
+    fn frames_a_removal_as_its_selector() {
+        let event = Event::from(RemoveFragments::new("#feedback"));
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-remove-fragments\ndata: selector #feedback\n\n"
+        );
+    }
+
+    #[test]
+    fn writes_every_merge_mode_datastar_accepts() {
+        let modes = [
+            (MergeMode::Inner, "inner"),
+            (MergeMode::Outer, "outer"),
+            (MergeMode::Prepend, "prepend"),
+            (MergeMode::Append, "append"),
+            (MergeMode::Before, "before"),
+            (MergeMode::After, "after"),
+        ];
+        for (mode, name) in modes {
+            let event = Event::from(MergeFragments::new("<p></p>").mode(mode));
+            assert!(
+                event
+                    .to_string()
+                    .contains(&format!("data: mergeMode {name}\n"))
+            );
+        }
+    }
+
+    #[test]
+    fn writes_signals_as_one_line_of_json() {
+        let event = Event::from(MergeSignals::new(serde_json::json!({"cursor": "2"})));
+        assert_eq!(
+            event.to_string(),
+            "event: datastar-merge-signals\ndata: signals {\"cursor\":\"2\"}\n\n"
+        );
+    }
+}
@@ -0,0 +1,549 @@
+//! Outbound HTTP for every upstream the site talks to (GitHub, ORCID, Resend).
+//!
+//! [`HttpClient`] adds per-host timeouts, jittered retries for idempotent
+//! requests and a per-host circuit breaker on top of an [`HttpTransport`],
+//! which performs the actual network call and can be replaced with a mock.
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use serde::{Serialize, de::DeserializeOwned};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const MAX_ATTEMPTS: u32 = 3;
+const BACKOFF_BASE_MS: u64 = 200;
+const BREAKER_THRESHOLD: u32 = 5;
+const BREAKER_COOLDOWN_SECS: i64 = 30;
+const MAX_RETRY_AFTER_SECS: u64 = 5;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Per-host request timeouts; unlisted hosts get [`DEFAULT_TIMEOUT`].
+const HOST_TIMEOUTS: &[(&str, Duration)] = &[
+    ("raw.githubusercontent.com", Duration::from_secs(5)),
+    ("api.github.com", Duration::from_secs(8)),
+    ("pub.orcid.org", Duration::from_secs(10)),
+    ("api.crossref.org", Duration::from_secs(8)),
+    ("api.openalex.org", Duration::from_secs(8)),
+    ("api.resend.com", Duration::from_secs(15)),
+];
+
+lazy_static! {
+    static ref CLIENT: Mutex<Arc<HttpClient>> =
+        Mutex::new(Arc::new(HttpClient::new(default_transport())));
+}
+
+/// The shared client used by every upstream fetch.
+pub fn client() -> Arc<HttpClient> {
+    CLIENT
+        .lock()
+        .map(|client| client.clone())
+        .unwrap_or_else(|_| Arc::new(HttpClient::new(default_transport())))
+}
+
+/// Swaps the shared client for one built on a mock transport.
+#[cfg(test)]
+pub fn install(client: HttpClient) {
+    if let Ok(mut shared) = CLIENT.lock() {
+        *shared = Arc::new(client);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Head,
+    Post,
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    /// Whether the request may be retried. GETs are; POSTs only when marked
+    /// with [`HttpRequest::idempotent`], like read-only GraphQL queries.
+    pub idempotent: bool,
+}
+
+impl HttpRequest {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: HttpMethod::Get,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            idempotent: true,
+        }
+    }
+
+    /// A GET without the body, for checking that a resource exists.
+    pub fn head(url: impl Into<String>) -> Self {
+        Self {
+            method: HttpMethod::Head,
+            ..Self::get(url)
+        }
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self {
+            method: HttpMethod::Post,
+            idempotent: false,
+            ..Self::get(url)
+        }
+    }
+
+    pub fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn json<T: Serialize>(self, body: &T) -> Self {
+        let mut request = self.header("Content-Type", "application/json");
+        request.body = serde_json::to_string(body).ok();
+        request
+    }
+
+    fn host(&self) -> String {
+        url::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.body)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HttpError {
+    #[error("request to {0} timed out")]
+    Timeout(String),
+    #[error("request failed: {0}")]
+    Transport(String),
+    #[error("circuit open for {0}, skipping request")]
+    CircuitOpen(String),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type HttpFuture<'a> = futures::future::BoxFuture<'a, Result<HttpResponse, HttpError>>;
+#[cfg(target_arch = "wasm32")]
+pub type HttpFuture<'a> = futures::future::LocalBoxFuture<'a, Result<HttpResponse, HttpError>>;
+
+/// Performs a single request with no retries or breaking.
+pub trait HttpTransport: Send + Sync {
+    fn send<'a>(&'a self, request: &'a HttpRequest, timeout: Duration) -> HttpFuture<'a>;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HttpTransport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest, timeout: Duration) -> HttpFuture<'a> {
+        Box::pin(async move {
+            let method = match request.method {
+                HttpMethod::Get => reqwest::Method::GET,
+                HttpMethod::Head => reqwest::Method::HEAD,
+                HttpMethod::Post => reqwest::Method::POST,
+            };
+            let mut builder = self.client.request(method, &request.url).timeout(timeout);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = &request.body {
+                builder = builder.body(body.clone());
+            }
+
+            let map_error = |error: reqwest::Error| {
+                if error.is_timeout() {
+                    HttpError::Timeout(request.url.clone())
+                } else {
+                    HttpError::Transport(error.to_string())
+                }
+            };
+            let response = builder.send().await.map_err(map_error)?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.text().await.map_err(map_error)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[derive(Default)]
+pub struct FetchTransport;
+
+#[cfg(target_arch = "wasm32")]
+impl HttpTransport for FetchTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest, timeout: Duration) -> HttpFuture<'a> {
+        use futures::future::{Either, select};
+        use worker::{AbortController, Delay, Fetch, Headers, Method, Request, RequestInit};
+
+        Box::pin(async move {
+            let transport_error = |error: worker::Error| HttpError::Transport(error.to_string());
+
+            let mut headers = Headers::new();
+            for (name, value) in &request.headers {
+                headers.set(name, value).map_err(transport_error)?;
+            }
+            let mut init = RequestInit::new();
+            init.with_method(match request.method {
+                HttpMethod::Get => Method::Get,
+                HttpMethod::Head => Method::Head,
+                HttpMethod::Post => Method::Post,
+            })
+            .with_headers(headers)
+            .with_body(request.body.as_deref().map(wasm_bindgen::JsValue::from_str));
+            let outbound = Request::new_with_init(&request.url, &init).map_err(transport_error)?;
+
+            let controller = AbortController::default();
+            let signal = controller.signal();
+            let outbound = Fetch::Request(outbound);
+            let fetch = outbound.send_with_signal(&signal);
+            let mut response = match select(Box::pin(fetch), Delay::from(timeout)).await {
+                Either::Left((response, _)) => response.map_err(transport_error)?,
+                Either::Right(_) => {
+                    controller.abort();
+                    return Err(HttpError::Timeout(request.url.clone()));
+                }
+            };
+
+            let status = response.status_code();
+            let headers = response.headers().entries().collect();
+            let body = response.text().await.map_err(transport_error)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+fn default_transport() -> impl HttpTransport {
+    #[cfg(not(target_arch = "wasm32"))]
+    return ReqwestTransport::default();
+    #[cfg(target_arch = "wasm32")]
+    return FetchTransport;
+}
+
+#[derive(Default)]
+struct Breaker {
+    failures: u32,
+    open_until: Option<DateTime<Utc>>,
+}
+
+pub struct HttpClient {
+    transport: Box<dyn HttpTransport>,
+    breakers: Mutex<HashMap<String, Breaker>>,
+}
+
+impl HttpClient {
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Box::new(transport),
+            breakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sends a request, retrying idempotent ones on timeouts, transport
+    /// errors and 429/502/503/504 responses with jittered exponential backoff.
+    /// Hosts that keep failing are skipped for a cooldown instead of making
+    /// every page wait on their timeout.
+    pub async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let host = request.host();
+        let timeout = HOST_TIMEOUTS
+            .iter()
+            .find(|(known, _)| *known == host)
+            .map_or(DEFAULT_TIMEOUT, |(_, timeout)| *timeout);
+        let attempts = if request.idempotent { MAX_ATTEMPTS } else { 1 };
+
+        let mut attempt = 0;
+        loop {
+            self.check_breaker(&host)?;
+            let result = self.transport.send(&request, timeout).await;
+            let (failed, retryable) = match &result {
+                Ok(response) => (
+                    response.status >= 500,
+                    matches!(response.status, 429 | 502 | 503 | 504),
+                ),
+                Err(_) => (true, true),
+            };
+            self.record(&host, failed);
+
+            attempt += 1;
+            if !retryable || attempt >= attempts {
+                return result;
+            }
+            // A short Retry-After is honoured; a long one means the caller is
+            // better off with stale content than waiting.
+            let retry_after = result
+                .as_ref()
+                .ok()
+                .and_then(|response| response.header("Retry-After"))
+                .and_then(|value| value.trim().parse::<u64>().ok());
+            match retry_after {
+                Some(secs) if secs > MAX_RETRY_AFTER_SECS => return result,
+                Some(secs) => sleep(Duration::from_secs(secs)).await,
+                None => sleep(backoff(attempt)).await,
+            }
+        }
+    }
+
+    fn check_breaker(&self, host: &str) -> Result<(), HttpError> {
+        let Ok(breakers) = self.breakers.lock() else {
+            return Ok(());
+        };
+        match breakers.get(host).and_then(|breaker| breaker.open_until) {
+            Some(open_until) if Utc::now() < open_until => {
+                Err(HttpError::CircuitOpen(host.to_string()))
+            }
+            // Past the cooldown the next request is let through as a probe;
+            // one more failure reopens the circuit straight away.
+            _ => Ok(()),
+        }
+    }
+
+    fn record(&self, host: &str, failed: bool) {
+        let Ok(mut breakers) = self.breakers.lock() else {
+            return;
+        };
+        if !failed {
+            breakers.remove(host);
+            return;
+        }
+        let breaker = breakers.entry(host.to_string()).or_default();
+        breaker.failures += 1;
+        if breaker.failures >= BREAKER_THRESHOLD {
+            breaker.open_until =
+                Some(Utc::now() + chrono::Duration::seconds(BREAKER_COOLDOWN_SECS));
+        }
+    }
+}
+
+/// Full-jitter backoff: a random delay up to `BACKOFF_BASE_MS * 2^attempt`.
+fn backoff(attempt: u32) -> Duration {
+    let ceiling = BACKOFF_BASE_MS << attempt;
+    let jitter = u64::from(Utc::now().timestamp_subsec_nanos()) % ceiling;
+    Duration::from_millis(jitter.max(BACKOFF_BASE_MS / 2))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    worker::Delay::from(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    lazy_static! {
+        /// Held by each test for as long as its client is installed.
+        static ref INSTALLED: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+    }
+
+    type Reply = fn(attempt: usize) -> Result<HttpResponse, HttpError>;
+
+    /// Answers the `n`th request with `reply(n)` and records the timeout each
+    /// was sent with.
+    struct MockTransport {
+        reply: Reply,
+        timeouts: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl HttpTransport for MockTransport {
+        fn send<'a>(&'a self, _request: &'a HttpRequest, timeout: Duration) -> HttpFuture<'a> {
+            let mut timeouts = self.timeouts.lock().unwrap();
+            timeouts.push(timeout);
+            let result = (self.reply)(timeouts.len());
+            Box::pin(async move { result })
+        }
+    }
+
+    fn status(status: u16) -> Result<HttpResponse, HttpError> {
+        Ok(HttpResponse {
+            status,
+            // Retry straight away rather than after a backoff.
+            headers: vec![("Retry-After".to_string(), "0".to_string())],
+            body: String::new(),
+        })
+    }
+
+    /// Installs a client on `reply`, returning the timeouts the transport
+    /// saw so far whenever the returned closure is called.
+    fn install_mock(reply: Reply) -> impl Fn() -> Vec<Duration> {
+        let timeouts = Arc::new(Mutex::new(Vec::new()));
+        install(HttpClient::new(MockTransport {
+            reply,
+            timeouts: timeouts.clone(),
+        }));
+        move || timeouts.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn retries_an_idempotent_request_up_to_max_attempts() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|_| status(503));
+
+        let response = client()
+            .send(HttpRequest::get("https://api.crossref.org/works"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, 503);
+        assert_eq!(calls().len(), MAX_ATTEMPTS as usize);
+    }
+
+    #[tokio::test]
+    async fn stops_retrying_once_a_request_succeeds() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|attempt| status(if attempt == 1 { 502 } else { 200 }));
+
+        let response = client()
+            .send(HttpRequest::get("https://api.openalex.org/works"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sends_a_post_once_unless_it_is_idempotent() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|_| status(503));
+
+        client()
+            .send(HttpRequest::post("https://api.resend.com/emails"))
+            .await
+            .unwrap();
+        assert_eq!(calls().len(), 1);
+
+        client()
+            .send(HttpRequest::post("https://api.resend.com/emails").idempotent())
+            .await
+            .unwrap();
+        assert_eq!(calls().len(), 1 + MAX_ATTEMPTS as usize);
+    }
+
+    #[tokio::test]
+    async fn retries_timeouts_with_the_host_timeout() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|_| Err(HttpError::Timeout("https://api.github.com".into())));
+
+        let result = client()
+            .send(HttpRequest::get("https://api.github.com/users/elcharitas"))
+            .await;
+
+        assert!(matches!(result, Err(HttpError::Timeout(_))));
+        assert_eq!(calls(), vec![Duration::from_secs(8); MAX_ATTEMPTS as usize]);
+    }
+
+    #[tokio::test]
+    async fn gives_unlisted_hosts_the_default_timeout() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|_| status(200));
+
+        client()
+            .send(HttpRequest::get("https://example.com/"))
+            .await
+            .unwrap();
+
+        assert_eq!(calls(), vec![DEFAULT_TIMEOUT]);
+    }
+
+    #[tokio::test]
+    async fn opens_the_circuit_after_repeated_failures() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|_| status(500));
+
+        for _ in 0..BREAKER_THRESHOLD {
+            let response = client()
+                .send(HttpRequest::post("https://pub.orcid.org/v3.0"))
+                .await
+                .unwrap();
+            assert_eq!(response.status, 500);
+        }
+        let result = client()
+            .send(HttpRequest::post("https://pub.orcid.org/v3.0"))
+            .await;
+
+        assert!(matches!(result, Err(HttpError::CircuitOpen(host)) if host == "pub.orcid.org"));
+        assert_eq!(calls().len(), BREAKER_THRESHOLD as usize);
+
+        // Other hosts are unaffected.
+        client()
+            .send(HttpRequest::get("https://api.crossref.org/works"))
+            .await
+            .unwrap();
+        assert_eq!(calls().len(), BREAKER_THRESHOLD as usize + 1);
+    }
+
+    #[tokio::test]
+    async fn a_success_resets_the_failure_count() {
+        let _installed = INSTALLED.lock().await;
+        let calls = install_mock(|attempt| {
+            status(if attempt == BREAKER_THRESHOLD as usize {
+                200
+            } else {
+                500
+            })
+        });
+
+        for _ in 0..2 * BREAKER_THRESHOLD - 2 {
+            client()
+                .send(HttpRequest::post("https://api.resend.com/emails"))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(calls().len(), 2 * BREAKER_THRESHOLD as usize - 2);
+    }
+}
@@ -1,13 +1,31 @@
 #[cfg(target_arch = "wasm32")]
+mod activity;
+#[cfg(target_arch = "wasm32")]
 mod analytics;
 #[cfg(target_arch = "wasm32")]
 mod app;
 #[cfg(target_arch = "wasm32")]
+mod cache;
+#[cfg(target_arch = "wasm32")]
+mod citation;
+#[cfg(target_arch = "wasm32")]
 mod components;
 #[cfg(target_arch = "wasm32")]
+mod datastar;
+#[cfg(target_arch = "wasm32")]
+mod http;
+#[cfg(target_arch = "wasm32")]
+mod orcid;
+#[cfg(target_arch = "wasm32")]
+mod overlay;
+#[cfg(target_arch = "wasm32")]
+mod publications;
+#[cfg(target_arch = "wasm32")]
 mod requests;
 #[cfg(target_arch = "wasm32")]
 mod shared;
+#[cfg(target_arch = "wasm32")]
+mod timeline;
 
 #[cfg(target_arch = "wasm32")]
 use tower::ServiceExt;
@@ -56,10 +74,10 @@ async fn main(req: HttpRequest, env: Env, _ctx: Context) -> Result<axum::respons
     }
 
     let router = app::create_router();
-    router
-        .oneshot(req)
-        .await
-        .map_err(|e| worker::Error::RustError(e.to_string()))
+    Ok(router.oneshot(req).await.unwrap_or_else(|error| {
+        use axum::response::IntoResponse;
+        app::error::AppError::Internal(error.to_string()).into_response()
+    }))
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -1,13 +1,31 @@
 #[cfg(not(target_arch = "wasm32"))]
+mod activity;
+#[cfg(not(target_arch = "wasm32"))]
 mod analytics;
 #[cfg(not(target_arch = "wasm32"))]
 mod app;
 #[cfg(not(target_arch = "wasm32"))]
+mod cache;
+#[cfg(not(target_arch = "wasm32"))]
+mod citation;
+#[cfg(not(target_arch = "wasm32"))]
 mod components;
 #[cfg(not(target_arch = "wasm32"))]
+mod datastar;
+#[cfg(not(target_arch = "wasm32"))]
+mod http;
+#[cfg(not(target_arch = "wasm32"))]
+mod orcid;
+#[cfg(not(target_arch = "wasm32"))]
+mod overlay;
+#[cfg(not(target_arch = "wasm32"))]
+mod publications;
+#[cfg(not(target_arch = "wasm32"))]
 mod requests;
 #[cfg(not(target_arch = "wasm32"))]
 mod shared;
+#[cfg(not(target_arch = "wasm32"))]
+mod timeline;
 
 #[cfg(not(target_arch = "wasm32"))]
 use app::create_router;
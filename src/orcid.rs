@@ -0,0 +1,154 @@
+//! The ORCID record beyond works: the person's biography, keywords and
+//! links, and their employment, education and funding history, for the
+//! resume.
+
+use crate::cache::TtlCache;
+use crate::publications::{CACHE_TTL, PublicationsError, find_path, get_json, string_at};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const ACCEPT: &str = "application/vnd.orcid+json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrcidProfile {
+    /// The credit name, or given and family names.
+    pub name: Option<String>,
+    pub biography: Option<String>,
+    pub keywords: Vec<String>,
+    /// `(label, url)` pairs from the researcher URLs.
+    pub links: Vec<(String, String)>,
+    pub employments: Vec<OrcidAffiliation>,
+    pub educations: Vec<OrcidAffiliation>,
+    pub fundings: Vec<OrcidFunding>,
+}
+
+/// An employment or education, most recent first as ORCID orders them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrcidAffiliation {
+    pub organization: String,
+    /// The job title or degree.
+    pub role: Option<String>,
+    pub department: Option<String>,
+    pub start_year: Option<String>,
+    /// `None` while ongoing.
+    pub end_year: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrcidFunding {
+    pub title: String,
+    /// e.g. `Grant` or `Award`.
+    pub funding_type: Option<String>,
+    pub funder: Option<String>,
+    pub start_year: Option<String>,
+    pub end_year: Option<String>,
+}
+
+lazy_static! {
+    static ref PROFILE_CACHE: TtlCache<(), OrcidProfile> = TtlCache::new(CACHE_TTL);
+}
+
+/// The profile for `orcid_id`, cached for a day like the works list and
+/// served stale while ORCID is unreachable.
+pub async fn fetch_orcid_profile(orcid_id: &str) -> Result<OrcidProfile, PublicationsError> {
+    PROFILE_CACHE
+        .get_or_refresh((), load_orcid_profile(orcid_id))
+        .await
+}
+
+async fn load_orcid_profile(orcid_id: &str) -> Result<OrcidProfile, PublicationsError> {
+    let [person, employments, educations, fundings] =
+        ["person", "employments", "educations", "fundings"]
+            .map(|section| format!("https://pub.orcid.org/v3.0/{orcid_id}/{section}"));
+    let (person, employments, educations, fundings) = futures::try_join!(
+        get_json(&person, ACCEPT),
+        get_json(&employments, ACCEPT),
+        get_json(&educations, ACCEPT),
+        get_json(&fundings, ACCEPT),
+    )?;
+
+    let profile = OrcidProfile {
+        name: string_at(&person, &[&["name", "credit-name", "value"]]).or_else(|| {
+            let names: Vec<String> = [
+                &["name", "given-names", "value"],
+                &["name", "family-name", "value"],
+            ]
+            .iter()
+            .filter_map(|path| string_at(&person, &[*path]))
+            .collect();
+            (!names.is_empty()).then(|| names.join(" "))
+        }),
+        biography: string_at(&person, &[&["biography", "content"]])
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty()),
+        keywords: items(&person, &["keywords", "keyword"])
+            .iter()
+            .filter_map(|keyword| string_at(keyword, &[&["content"]]))
+            .collect(),
+        links: items(&person, &["researcher-urls", "researcher-url"])
+            .iter()
+            .filter_map(|link| {
+                let url = string_at(link, &[&["url", "value"]])?;
+                let label = string_at(link, &[&["url-name"]]).unwrap_or_else(|| url.clone());
+                Some((label, url))
+            })
+            .collect(),
+        employments: extract_affiliations(&employments, "employment-summary"),
+        educations: extract_affiliations(&educations, "education-summary"),
+        fundings: extract_fundings(&fundings),
+    };
+    Ok(profile)
+}
+
+fn items<'a>(value: &'a Value, path: &[&str]) -> &'a [Value] {
+    find_path(value, path)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn year(summary: &Value, date: &str) -> Option<String> {
+    string_at(summary, &[&[date, "year", "value"]])
+}
+
+/// The owner's preferred summary of each affiliation group; `kind` is the
+/// summary's key, e.g. `employment-summary`.
+fn extract_affiliations(payload: &Value, kind: &str) -> Vec<OrcidAffiliation> {
+    items(payload, &["affiliation-group"])
+        .iter()
+        .filter_map(|group| {
+            let summary = items(group, &["summaries"]).first()?.get(kind)?;
+            Some(OrcidAffiliation {
+                organization: string_at(summary, &[&["organization", "name"]])?,
+                role: string_at(summary, &[&["role-title"]]),
+                department: string_at(summary, &[&["department-name"]]),
+                start_year: year(summary, "start-date"),
+                end_year: year(summary, "end-date"),
+            })
+        })
+        .collect()
+}
+
+fn extract_fundings(payload: &Value) -> Vec<OrcidFunding> {
+    items(payload, &["group"])
+        .iter()
+        .filter_map(|group| {
+            let summary = items(group, &["funding-summary"]).first()?;
+            Some(OrcidFunding {
+                title: string_at(summary, &[&["title", "title", "value"]])?,
+                funding_type: string_at(summary, &[&["type"]]).map(|kind| {
+                    let kind = kind.replace('_', " ").to_lowercase();
+                    let mut chars = kind.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                }),
+                funder: string_at(summary, &[&["organization", "name"]]),
+                start_year: year(summary, "start-date"),
+                end_year: year(summary, "end-date"),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+use crate::shared::Project;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+/// Hand-curated changes to the GitHub project listing, read from
+/// `app/projects.json`. Entries are matched to projects by repository URL.
+#[derive(Debug, Default, Deserialize)]
+pub struct ProjectOverlay {
+    /// Name of the project the home page features.
+    pub featured: Option<String>,
+    #[serde(default)]
+    pub projects: Vec<ProjectOverride>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProjectOverride {
+    /// The repository. One outside github.com is listed from this entry
+    /// alone, so it should at least carry a description.
+    pub url: String,
+    /// Listing name and `/projects/{name}` slug; the repository name if unset.
+    pub name: Option<String>,
+    pub hidden: bool,
+    pub pinned: bool,
+    /// Projects with an order lead the listing, lowest first.
+    pub order: Option<i32>,
+    /// List the repository even when the GitHub listing doesn't, e.g. one
+    /// owned by an organisation or without stars.
+    pub include: bool,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub language: Option<String>,
+    pub group: Option<String>,
+    pub screenshots: Vec<String>,
+}
+
+static OVERLAY: LazyLock<ProjectOverlay> = LazyLock::new(|| {
+    let json = include_str!("./app/projects.json");
+    serde_json::from_str(json).expect("projects.json is valid")
+});
+
+pub fn load_overlay() -> &'static ProjectOverlay {
+    &OVERLAY
+}
+
+impl ProjectOverlay {
+    pub fn find(&self, url: &str) -> Option<&ProjectOverride> {
+        self.projects.iter().find(|entry| entry.matches(url))
+    }
+
+    /// The entry listed under `name`, which may differ from its repository.
+    pub fn named(&self, name: &str) -> Option<&ProjectOverride> {
+        self.projects
+            .iter()
+            .find(|entry| entry.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Entries to list even when GitHub doesn't return them.
+    pub fn includes(&self) -> impl Iterator<Item = &ProjectOverride> {
+        self.projects
+            .iter()
+            .filter(|entry| entry.include && !entry.hidden)
+    }
+
+    /// Drops hidden projects and applies each entry to its project.
+    pub fn apply(&self, projects: Vec<Project>) -> Vec<Project> {
+        projects
+            .into_iter()
+            .filter_map(|project| match self.find(&project.url) {
+                Some(entry) if entry.hidden => None,
+                Some(entry) => Some(entry.apply(project)),
+                None => Some(project),
+            })
+            .collect()
+    }
+
+    /// Ordered projects first, then pinned ones; the sort is stable, so the
+    /// rest keep the listing's order.
+    pub fn rank(&self, projects: &mut [Project]) {
+        projects.sort_by_key(|project| {
+            let order = self.find(&project.url).and_then(|entry| entry.order);
+            (order.is_none(), order, !project.pinned)
+        });
+    }
+}
+
+impl ProjectOverride {
+    pub fn matches(&self, url: &str) -> bool {
+        same_repository(&self.url, url)
+    }
+
+    pub fn name(&self) -> &str {
+        match &self.name {
+            Some(name) => name,
+            None => self
+                .url
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn apply(&self, mut project: Project) -> Project {
+        project.name = self.name().to_string();
+        project.pinned |= self.pinned;
+        if let Some(description) = &self.description {
+            project.description = description.clone();
+        }
+        if let Some(homepage) = &self.homepage {
+            project.homepage = homepage.clone();
+        }
+        if let Some(tags) = &self.tags {
+            project.tags = tags.clone();
+        }
+        if self.language.is_some() {
+            project.language = self.language.clone();
+        }
+        if self.group.is_some() {
+            project.group = self.group.clone();
+        }
+        if !self.screenshots.is_empty() {
+            project.screenshots = self.screenshots.clone();
+        }
+        project
+    }
+
+    /// The project as this entry describes it, for repositories GitHub can't
+    /// tell us about.
+    pub fn to_project(&self) -> Project {
+        self.apply(Project {
+            url: self.url.clone(),
+            ..Project::default()
+        })
+    }
+}
+
+/// Whether two repository URLs name the same repository, ignoring case, a
+/// trailing slash and a `.git` suffix.
+fn same_repository(a: &str, b: &str) -> bool {
+    let normalize = |url: &str| {
+        let url = url.trim_end_matches('/');
+        url.strip_suffix(".git").unwrap_or(url).to_lowercase()
+    };
+    normalize(a) == normalize(b)
+}
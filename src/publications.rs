@@ -0,0 +1,884 @@
+//! The publication list: ORCID works merged with Crossref metadata, OpenAlex
+//! citation counts and abstracts, and entries from `app/publications.json`
+//! for talks, preprints and posters ORCID doesn't have.
+//!
+//! Records from every source are matched into works by DOI, or by title when
+//! one side has no DOI. Each field of a work comes from the most preferred
+//! source that has it, in the order the file's `prefer` lists.
+
+use crate::cache::TtlCache;
+use crate::http::{self, HttpError, HttpRequest};
+use crate::requests::log_warning;
+use crate::shared::{count_facets, slugify};
+use futures::StreamExt;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const CACHE_TTL: i64 = 86400;
+/// DOIs looked up in Crossref and OpenAlex at once.
+const LOOKUP_CONCURRENCY: usize = 4;
+const USER_AGENT: &str = "elcharitas-wtf (https://elcharitas.wtf)";
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublicationsError {
+    #[error("Request error: {0}")]
+    RequestError(#[from] HttpError),
+    #[error("JSON parsing error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("{url} responded with status {status}")]
+    Status { url: String, status: u16 },
+    #[error("{0} returned no work")]
+    MissingWork(String),
+    #[error("ORCID work {0} has no title")]
+    Untitled(u64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublicationSource {
+    /// `app/publications.json`.
+    Manual,
+    Orcid,
+    Crossref,
+    OpenAlex,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PublicationEntry {
+    pub title: String,
+    pub venue: Option<String>,
+    pub published_on: Option<String>,
+    pub work_type: Option<String>,
+    pub url: Option<String>,
+    /// Lowercase and without a resolver prefix, e.g. `10.1000/xyz`.
+    pub doi: Option<String>,
+    /// Full names, given names first, in byline order.
+    pub authors: Vec<String>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    /// A page or a range such as `45-67`.
+    pub pages: Option<String>,
+    #[serde(rename = "abstract")]
+    pub summary: Option<String>,
+    /// Subjects and keywords from every source, for the page's focus areas.
+    pub keywords: Vec<String>,
+    pub citations: Option<u32>,
+    /// ORCID's `put-code` for the owner's preferred record of the work, which
+    /// its full record is fetched by.
+    pub put_code: Option<u64>,
+    /// The sources the entry was merged from, most preferred first.
+    pub sources: Vec<PublicationSource>,
+    /// The work's slug, used in its URLs and unique among the works
+    /// [`fetch_publications`] returns. Set by [`assign_ids`] once the list is
+    /// merged.
+    pub id: String,
+}
+
+impl PublicationEntry {
+    pub fn year(&self) -> Option<&str> {
+        self.published_on.as_deref().and_then(|date| date.get(..4))
+    }
+}
+
+/// Counts of works by type, venue, year and keyword, most common first
+/// (years newest first).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PublicationFacets {
+    pub work_types: Vec<(String, usize)>,
+    pub venues: Vec<(String, usize)>,
+    pub years: Vec<(String, usize)>,
+    pub keywords: Vec<(String, usize)>,
+}
+
+impl PublicationFacets {
+    pub fn new(publications: &[PublicationEntry]) -> Self {
+        let mut years = count_facets(publications.iter().filter_map(PublicationEntry::year));
+        years.sort_by(|a, b| b.0.cmp(&a.0));
+        Self {
+            work_types: count_facets(
+                publications
+                    .iter()
+                    .filter_map(|entry| entry.work_type.as_deref()),
+            ),
+            venues: count_facets(
+                publications
+                    .iter()
+                    .filter_map(|entry| entry.venue.as_deref()),
+            ),
+            years,
+            keywords: count_facets(
+                publications
+                    .iter()
+                    .flat_map(|entry| entry.keywords.iter().map(String::as_str)),
+            ),
+        }
+    }
+}
+
+/// `app/publications.json`.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct PublicationsConfig {
+    /// Sources in the order their details win; unlisted ones come last.
+    prefer: Vec<PublicationSource>,
+    works: Vec<PublicationEntry>,
+}
+
+impl Default for PublicationsConfig {
+    fn default() -> Self {
+        Self {
+            prefer: vec![
+                PublicationSource::Manual,
+                PublicationSource::Crossref,
+                PublicationSource::Orcid,
+                PublicationSource::OpenAlex,
+            ],
+            works: Vec::new(),
+        }
+    }
+}
+
+impl PublicationsConfig {
+    fn rank(&self, source: PublicationSource) -> usize {
+        self.prefer
+            .iter()
+            .position(|preferred| *preferred == source)
+            .unwrap_or(self.prefer.len())
+    }
+}
+
+fn load_config() -> PublicationsConfig {
+    let json = include_str!("./app/publications.json");
+    serde_json::from_str(json).expect("publications.json is valid")
+}
+
+pub fn normalize_orcid_id(orcid_id: &str) -> String {
+    orcid_id
+        .trim()
+        .trim_start_matches("https://orcid.org/")
+        .trim_start_matches("http://orcid.org/")
+        .trim_matches('/')
+        .to_string()
+}
+
+pub fn normalize_doi(doi: &str) -> Option<String> {
+    let doi = doi.trim().to_lowercase();
+    let doi = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "http://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| doi.strip_prefix(prefix))
+    .unwrap_or(&doi)
+    .trim();
+    doi.starts_with("10.").then(|| doi.to_string())
+}
+
+/// Lowercase letters and digits separated by single spaces, so punctuation
+/// and capitalisation differences between sources don't split a work.
+fn normalize_title(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn same_work(a: &PublicationEntry, b: &PublicationEntry) -> bool {
+    match (&a.doi, &b.doi) {
+        (Some(a), Some(b)) => a == b,
+        _ => {
+            let title = normalize_title(&a.title);
+            !title.is_empty() && title == normalize_title(&b.title)
+        }
+    }
+}
+
+lazy_static! {
+    static ref PUBLICATIONS_CACHE: TtlCache<(), Vec<PublicationEntry>> = TtlCache::new(CACHE_TTL);
+    /// Full ORCID work records by put-code.
+    static ref WORK_CACHE: TtlCache<u64, PublicationEntry> = TtlCache::new(CACHE_TTL);
+}
+
+/// Every work, newest first. Without an ORCID iD only the hand-listed works
+/// are looked up.
+pub async fn fetch_publications(
+    orcid_id: &str,
+) -> Result<Vec<PublicationEntry>, PublicationsError> {
+    PUBLICATIONS_CACHE
+        .get_or_refresh((), load_publications(orcid_id))
+        .await
+}
+
+async fn load_publications(orcid_id: &str) -> Result<Vec<PublicationEntry>, PublicationsError> {
+    let config = load_config();
+    let mut records: Vec<PublicationEntry> = config
+        .works
+        .iter()
+        .cloned()
+        .map(|work| PublicationEntry {
+            doi: work.doi.as_deref().and_then(normalize_doi),
+            sources: vec![PublicationSource::Manual],
+            ..work
+        })
+        .collect();
+    if !orcid_id.is_empty() {
+        records.extend(fetch_orcid_publications(orcid_id, &config).await?);
+    }
+
+    let mut works: Vec<Vec<PublicationEntry>> = Vec::new();
+    for record in records {
+        match works
+            .iter_mut()
+            .find(|work| work.iter().any(|other| same_work(other, &record)))
+        {
+            Some(work) => work.push(record),
+            None => works.push(vec![record]),
+        }
+    }
+    let works: Vec<Vec<PublicationEntry>> = futures::stream::iter(works)
+        .map(|mut work| async move {
+            if let Some(doi) = work.iter().find_map(|record| record.doi.clone()) {
+                let (crossref, openalex) =
+                    futures::join!(fetch_crossref_work(&doi), fetch_openalex_work(&doi));
+                for lookup in [crossref, openalex] {
+                    match lookup {
+                        Ok(record) => work.push(record),
+                        Err(error) => log_warning(&format!("looking up {doi}: {error}")),
+                    }
+                }
+            }
+            work
+        })
+        .buffered(LOOKUP_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut publications: Vec<PublicationEntry> =
+        works.into_iter().map(|work| merge(work, &config)).collect();
+    publications.sort_by(|a, b| b.published_on.cmp(&a.published_on));
+    assign_ids(&mut publications);
+    Ok(publications)
+}
+
+/// Gives each work a slug from its DOI, else its ORCID put-code, else its
+/// title. Works that would share one are told apart by `-2`, `-3` and so on,
+/// counting from the oldest so a new work never takes an existing URL.
+fn assign_ids(publications: &mut [PublicationEntry]) {
+    let mut taken = std::collections::HashSet::new();
+    for entry in publications.iter_mut().rev() {
+        let base = match (&entry.doi, entry.put_code) {
+            (Some(doi), _) => slugify(doi),
+            (None, Some(put_code)) => format!("orcid-{put_code}"),
+            (None, None) => slugify(&entry.title),
+        };
+        let base = if base.is_empty() {
+            "work".to_string()
+        } else {
+            base
+        };
+        let mut slug = base.clone();
+        let mut suffix = 1;
+        while !taken.insert(slug.clone()) {
+            suffix += 1;
+            slug = format!("{base}-{suffix}");
+        }
+        entry.id = slug;
+    }
+}
+
+/// The work whose [`PublicationEntry::id`] is `id`, filled in from its full
+/// ORCID record: the contributors, abstract and journal issue the works list
+/// leaves out. The listed entry is served alone when the record can't be
+/// fetched.
+pub async fn fetch_publication(
+    orcid_id: &str,
+    id: &str,
+) -> Result<Option<PublicationEntry>, PublicationsError> {
+    let Some(entry) = fetch_publications(orcid_id)
+        .await?
+        .into_iter()
+        .find(|entry| entry.id == id)
+    else {
+        return Ok(None);
+    };
+    let Some(put_code) = entry.put_code.filter(|_| !orcid_id.is_empty()) else {
+        return Ok(Some(entry));
+    };
+    match fetch_orcid_work(orcid_id, put_code).await {
+        Ok(record) => Ok(Some(merge(vec![entry, record], &load_config()))),
+        Err(error) => {
+            log_warning(&format!("fetching ORCID work {put_code}: {error}"));
+            Ok(Some(entry))
+        }
+    }
+}
+
+/// One entry from a work's records, each field from the most preferred
+/// record that has it.
+fn merge(mut records: Vec<PublicationEntry>, config: &PublicationsConfig) -> PublicationEntry {
+    // Stable, so records from one source keep their own order.
+    records.sort_by_key(|record| record.sources.first().map(|source| config.rank(*source)));
+    let mut merged = PublicationEntry::default();
+    for record in records {
+        if merged.title.is_empty() {
+            merged.title = record.title;
+        }
+        if merged.id.is_empty() {
+            merged.id = record.id;
+        }
+        if merged.authors.is_empty() {
+            merged.authors = record.authors;
+        }
+        merged.venue = merged.venue.or(record.venue);
+        merged.published_on = merged.published_on.or(record.published_on);
+        merged.work_type = merged.work_type.or(record.work_type);
+        merged.url = merged.url.or(record.url);
+        merged.doi = merged.doi.or(record.doi);
+        merged.volume = merged.volume.or(record.volume);
+        merged.issue = merged.issue.or(record.issue);
+        merged.pages = merged.pages.or(record.pages);
+        merged.summary = merged.summary.or(record.summary);
+        merged.citations = merged.citations.or(record.citations);
+        for keyword in record.keywords {
+            if !merged
+                .keywords
+                .iter()
+                .any(|seen| seen.eq_ignore_ascii_case(&keyword))
+            {
+                merged.keywords.push(keyword);
+            }
+        }
+        merged.put_code = merged.put_code.or(record.put_code);
+        for source in record.sources {
+            if !merged.sources.contains(&source) {
+                merged.sources.push(source);
+            }
+        }
+    }
+    if merged.url.is_none() {
+        merged.url = merged
+            .doi
+            .as_ref()
+            .map(|doi| format!("https://doi.org/{doi}"));
+    }
+    merged
+}
+
+/// GETs `url` as JSON, identifying the site as the polite APIs ask.
+pub async fn get_json(url: &str, accept: &str) -> Result<Value, PublicationsError> {
+    let response = http::client()
+        .send(
+            HttpRequest::get(url)
+                .header("Accept", accept)
+                .header("User-Agent", USER_AGENT),
+        )
+        .await?;
+    if !response.is_success() {
+        return Err(PublicationsError::Status {
+            url: url.to_string(),
+            status: response.status,
+        });
+    }
+
+    Ok(response.json()?)
+}
+
+/// One entry per work on the ORCID record. ORCID groups the same work from
+/// several sources, so each group is merged first, the owner's preferred
+/// version winning, and only then matched against the other sources.
+async fn fetch_orcid_publications(
+    orcid_id: &str,
+    config: &PublicationsConfig,
+) -> Result<Vec<PublicationEntry>, PublicationsError> {
+    let url = format!("https://pub.orcid.org/v3.0/{orcid_id}/works");
+    let payload = get_json(&url, "application/vnd.orcid+json").await?;
+    let groups = payload
+        .get("group")
+        .or_else(|| payload.get("groups"))
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    Ok(groups
+        .iter()
+        .map(extract_group_entries)
+        .filter(|records| !records.is_empty())
+        .map(|records| merge(records, config))
+        .collect())
+}
+
+async fn fetch_orcid_work(
+    orcid_id: &str,
+    put_code: u64,
+) -> Result<PublicationEntry, PublicationsError> {
+    WORK_CACHE
+        .get_or_refresh(put_code, load_orcid_work(orcid_id, put_code))
+        .await
+}
+
+async fn load_orcid_work(
+    orcid_id: &str,
+    put_code: u64,
+) -> Result<PublicationEntry, PublicationsError> {
+    let url = format!("https://pub.orcid.org/v3.0/{orcid_id}/work/{put_code}");
+    let payload = get_json(&url, "application/vnd.orcid+json").await?;
+    let mut work =
+        extract_publication_entry(&payload).ok_or(PublicationsError::Untitled(put_code))?;
+    work.authors = extract_contributors(&payload);
+    work.summary = string_at(&payload, &[&["short-description"], &["shortDescription"]])
+        .map(|text| strip_markup(&text))
+        .filter(|text| !text.is_empty());
+    // ORCID has no fields for the issue; owners paste it in a BibTeX citation.
+    let bibtex = string_at(
+        &payload,
+        &[
+            &["citation", "citation-type"],
+            &["citation", "citationType"],
+        ],
+    )
+    .filter(|kind| kind.eq_ignore_ascii_case("bibtex"))
+    .and_then(|_| {
+        string_at(
+            &payload,
+            &[
+                &["citation", "citation-value"],
+                &["citation", "citationValue"],
+            ],
+        )
+    });
+    if let Some(bibtex) = bibtex {
+        work.volume = bibtex_field(&bibtex, "volume");
+        work.issue = bibtex_field(&bibtex, "number");
+        work.pages = bibtex_field(&bibtex, "pages").map(|pages| pages.replace("--", "-"));
+    }
+    Ok(work)
+}
+
+/// The credited names of a work's authors; editors and other roles are left
+/// out.
+fn extract_contributors(work: &Value) -> Vec<String> {
+    find_path(work, &["contributors", "contributor"])
+        .and_then(Value::as_array)
+        .map(|contributors| {
+            contributors
+                .iter()
+                .filter(|contributor| {
+                    string_at(
+                        contributor,
+                        &[
+                            &["contributor-attributes", "contributor-role"],
+                            &["contributorAttributes", "contributorRole"],
+                        ],
+                    )
+                    .is_none_or(|role| role.to_lowercase().contains("author"))
+                })
+                .filter_map(|contributor| {
+                    string_at(
+                        contributor,
+                        &[&["credit-name", "value"], &["creditName", "value"]],
+                    )
+                })
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The value of `field` in a BibTeX entry, without its braces or quotes.
+fn bibtex_field(bibtex: &str, field: &str) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets valid in `bibtex`.
+    let lower = bibtex.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(found) = lower[from..].find(field) {
+        let start = from + found;
+        from = start + field.len();
+        if lower[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        {
+            continue;
+        }
+        let Some(rest) = bibtex[from..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let value = if let Some(rest) = rest.strip_prefix('{') {
+            let mut depth = 1;
+            let end = rest.find(|c| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })?;
+            rest[..end].replace(['{', '}'], "")
+        } else if let Some(rest) = rest.strip_prefix('"') {
+            rest.split('"').next()?.to_string()
+        } else {
+            rest.split([',', '}', '\n']).next()?.to_string()
+        };
+        let value = value.trim();
+        return (!value.is_empty()).then(|| value.to_string());
+    }
+    None
+}
+
+fn extract_group_entries(group: &Value) -> Vec<PublicationEntry> {
+    let mut summaries: Vec<&Value> = group
+        .get("work-summary")
+        .or_else(|| group.get("workSummary"))
+        .and_then(Value::as_array)
+        .map(|summaries| summaries.iter().collect())
+        .unwrap_or_default();
+    summaries.sort_by_key(|summary| {
+        std::cmp::Reverse(
+            string_at(summary, &[&["display-index"], &["displayIndex"]])
+                .and_then(|index| index.parse::<i64>().ok())
+                .unwrap_or_default(),
+        )
+    });
+    let group_doi = extract_doi(group);
+
+    summaries
+        .into_iter()
+        .filter_map(extract_publication_entry)
+        .map(|entry| PublicationEntry {
+            doi: entry.doi.or_else(|| group_doi.clone()),
+            ..entry
+        })
+        .collect()
+}
+
+fn extract_publication_entry(summary: &Value) -> Option<PublicationEntry> {
+    let title = string_at(
+        summary,
+        &[
+            &["title", "title", "value"],
+            &["work-title", "title", "value"],
+            &["title", "value"],
+            &["workTitle", "title", "value"],
+        ],
+    )?;
+    let venue = string_at(
+        summary,
+        &[
+            &["journal-title", "value"],
+            &["journalTitle", "value"],
+            &["venue", "value"],
+        ],
+    );
+    let published_on = extract_publication_date(summary);
+    let work_type = string_at(summary, &[&["type"], &["work-type"], &["workType"]])
+        .map(|value| humanize_work_type(&value));
+    let url = string_at(summary, &[&["url", "value"], &["url"], &["path"]]);
+
+    Some(PublicationEntry {
+        title,
+        venue,
+        published_on,
+        work_type,
+        url,
+        doi: extract_doi(summary),
+        put_code: string_at(summary, &[&["put-code"], &["putCode"]])
+            .and_then(|code| code.parse().ok()),
+        sources: vec![PublicationSource::Orcid],
+        ..Default::default()
+    })
+}
+
+/// The DOI among a group's or summary's external identifiers.
+fn extract_doi(value: &Value) -> Option<String> {
+    let ids = find_path(value, &["external-ids", "external-id"])
+        .or_else(|| find_path(value, &["externalIds", "externalId"]))
+        .and_then(Value::as_array)?;
+    ids.iter()
+        .filter(|id| {
+            string_at(id, &[&["external-id-type"], &["externalIdType"]])
+                .is_some_and(|kind| kind.eq_ignore_ascii_case("doi"))
+        })
+        .find_map(|id| {
+            string_at(id, &[&["external-id-value"], &["externalIdValue"]])
+                .and_then(|doi| normalize_doi(&doi))
+        })
+}
+
+fn extract_publication_date(summary: &Value) -> Option<String> {
+    let year = string_at(
+        summary,
+        &[
+            &["publication-date", "year", "value"],
+            &["publicationDate", "year", "value"],
+        ],
+    )?;
+    let month = string_at(
+        summary,
+        &[
+            &["publication-date", "month", "value"],
+            &["publicationDate", "month", "value"],
+        ],
+    );
+    let day = string_at(
+        summary,
+        &[
+            &["publication-date", "day", "value"],
+            &["publicationDate", "day", "value"],
+        ],
+    );
+
+    Some(match (month, day) {
+        (Some(month), Some(day)) => format!("{year}-{month:0>2}-{day:0>2}"),
+        (Some(month), None) => format!("{year}-{month:0>2}"),
+        _ => year,
+    })
+}
+
+async fn fetch_crossref_work(doi: &str) -> Result<PublicationEntry, PublicationsError> {
+    let url = format!("https://api.crossref.org/works/{doi}");
+    let payload = get_json(&url, "application/json").await?;
+    let work = payload
+        .get("message")
+        .ok_or(PublicationsError::MissingWork(url))?;
+    let first = |key: &str| {
+        work.get(key)
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(Value::as_str)
+            .map(strip_markup)
+            .filter(|value| !value.is_empty())
+    };
+    // `date-parts` is `[[year, month, day]]`, with month and day optional.
+    let published_on = ["published", "issued"].iter().find_map(|key| {
+        let parts = find_path(work, &[key, "date-parts"])?
+            .get(0)?
+            .as_array()?
+            .iter()
+            .map(Value::as_u64)
+            .collect::<Option<Vec<_>>>()?;
+        match parts.as_slice() {
+            [year, month, day, ..] => Some(format!("{year}-{month:02}-{day:02}")),
+            [year, month] => Some(format!("{year}-{month:02}")),
+            [year] => Some(year.to_string()),
+            [] => None,
+        }
+    });
+    let authors = work
+        .get("author")
+        .and_then(Value::as_array)
+        .map(|authors| {
+            authors
+                .iter()
+                .filter_map(|author| {
+                    let name = [author.get("given"), author.get("family")]
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    (!name.is_empty()).then_some(name)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(PublicationEntry {
+        title: first("title").unwrap_or_default(),
+        venue: first("container-title"),
+        published_on,
+        work_type: string_at(work, &[&["type"]]).map(|value| humanize_work_type(&value)),
+        url: string_at(work, &[&["URL"]]),
+        doi: Some(doi.to_string()),
+        authors,
+        volume: string_at(work, &[&["volume"]]),
+        issue: string_at(work, &[&["issue"]]),
+        pages: string_at(work, &[&["page"]]),
+        summary: string_at(work, &[&["abstract"]])
+            .map(|summary| strip_markup(&summary))
+            .filter(|summary| !summary.is_empty()),
+        keywords: work
+            .get("subject")
+            .and_then(Value::as_array)
+            .map(|subjects| {
+                subjects
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        citations: None,
+        put_code: None,
+        sources: vec![PublicationSource::Crossref],
+        id: String::new(),
+    })
+}
+
+async fn fetch_openalex_work(doi: &str) -> Result<PublicationEntry, PublicationsError> {
+    let url = format!("https://api.openalex.org/works/https://doi.org/{doi}");
+    let work = get_json(&url, "application/json").await?;
+    let authors = work
+        .get("authorships")
+        .and_then(Value::as_array)
+        .map(|authorships| {
+            authorships
+                .iter()
+                .filter_map(|authorship| string_at(authorship, &[&["author", "display_name"]]))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(PublicationEntry {
+        title: string_at(&work, &[&["display_name"], &["title"]]).unwrap_or_default(),
+        venue: string_at(&work, &[&["primary_location", "source", "display_name"]]),
+        published_on: string_at(&work, &[&["publication_date"]]),
+        work_type: string_at(&work, &[&["type"]]).map(|value| humanize_work_type(&value)),
+        url: string_at(&work, &[&["primary_location", "landing_page_url"]]),
+        doi: Some(doi.to_string()),
+        authors,
+        volume: string_at(&work, &[&["biblio", "volume"]]),
+        issue: string_at(&work, &[&["biblio", "issue"]]),
+        pages: match (
+            string_at(&work, &[&["biblio", "first_page"]]),
+            string_at(&work, &[&["biblio", "last_page"]]),
+        ) {
+            (Some(first), Some(last)) if first != last => Some(format!("{first}-{last}")),
+            (first, last) => first.or(last),
+        },
+        summary: work
+            .get("abstract_inverted_index")
+            .and_then(rebuild_abstract),
+        citations: work
+            .get("cited_by_count")
+            .and_then(Value::as_u64)
+            .map(|count| count as u32),
+        // Older works have topics but no keywords.
+        keywords: ["keywords", "topics"]
+            .iter()
+            .map(|key| display_names(&work, key))
+            .find(|names| !names.is_empty())
+            .unwrap_or_default(),
+        put_code: None,
+        sources: vec![PublicationSource::OpenAlex],
+        id: String::new(),
+    })
+}
+
+/// The `display_name` of every object in the array at `key`.
+fn display_names(value: &Value, key: &str) -> Vec<String> {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| string_at(item, &[&["display_name"]]))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// OpenAlex ships abstracts as `{word: [positions]}`.
+fn rebuild_abstract(index: &Value) -> Option<String> {
+    let mut words: Vec<(u64, &str)> = index
+        .as_object()?
+        .iter()
+        .flat_map(|(word, positions)| {
+            positions
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_u64)
+                .map(move |position| (position, word.as_str()))
+        })
+        .collect();
+    words.sort_by_key(|(position, _)| *position);
+    let summary = words
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!summary.is_empty()).then_some(summary)
+}
+
+/// Text without tags, for Crossref's JATS abstracts and titles with markup.
+/// Paragraph and section tags become spaces; inline ones like `<i>` vanish.
+fn strip_markup(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut tag: Option<String> = None;
+    for c in text.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                let name = name.trim_start_matches('/');
+                let name = name.split([' ', '/']).next().unwrap_or_default();
+                let name = name.rsplit(':').next().unwrap_or_default();
+                if matches!(name, "p" | "title" | "sec" | "br" | "list-item") {
+                    plain.push(' ');
+                }
+                tag = None;
+            }
+            (Some(name), _) => name.push(c),
+            (None, _) => plain.push(c),
+        }
+    }
+    plain.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn humanize_work_type(work_type: &str) -> String {
+    work_type
+        .split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The text at the first of `paths` that leads to any, since ORCID and the
+/// other APIs spell keys several ways.
+pub fn string_at(value: &Value, paths: &[&[&str]]) -> Option<String> {
+    for path in paths {
+        if let Some(found) = find_path(value, path).and_then(value_to_string) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+pub fn find_path<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    let mut current = value;
+    for key in path {
+        current = current.get(*key)?;
+    }
+    Some(current)
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Object(map) => {
+            for key in ["value", "title", "name", "url", "path", "type"] {
+                if let Some(text) = map.get(key).and_then(value_to_string) {
+                    return Some(text);
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
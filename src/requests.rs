@@ -1,7 +1,16 @@
-use reqwest;
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::HashMap;
+use std::sync::{Mutex, Once, PoisonError};
 
-use crate::shared::{Content, Post, Project, Tag, get_env};
+use crate::cache::TtlCache;
+use crate::http::{self, HttpError, HttpRequest, HttpResponse};
+use crate::overlay::{ProjectOverride, load_overlay};
+use crate::shared::{
+    ACTIVITY_WEEKS, Content, LanguageShare, Post, PostCoverImage, Project, ProjectActivity,
+    ProjectQuery, ProjectSort, Tag, count_facets, get_env, render_markdown,
+};
 
 #[derive(Debug, Deserialize)]
 struct PostMeta {
@@ -11,6 +20,9 @@ struct PostMeta {
     read_time_in_minutes: i32,
     category: Option<String>,
     published_at: Option<String>,
+    /// The cover from the post's frontmatter, so listings can show it
+    /// without fetching every post.
+    cover: Option<String>,
 }
 
 fn post_meta_to_post(m: PostMeta) -> Post {
@@ -38,7 +50,7 @@ fn post_meta_to_post(m: PostMeta) -> Post {
         response_count: None,
         views: None,
         seo: None,
-        cover_image: None,
+        cover_image: m.cover.map(|url| PostCoverImage { url: Some(url) }),
         author: None,
         content: None,
         og_meta_data: None,
@@ -47,46 +59,133 @@ fn post_meta_to_post(m: PostMeta) -> Post {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-async fn http_get_text(url: &str) -> Option<String> {
-    use worker::{Fetch, Method, Request, RequestInit};
-    let req = Request::new_with_init(url, RequestInit::new().with_method(Method::Get)).ok()?;
-    let mut resp = Fetch::Request(req).send().await.ok()?;
-    if resp.status_code() >= 400 {
-        return None;
+/// The last successful response for a URL, with its ETag for conditional
+/// requests.
+struct Snapshot {
+    body: String,
+    etag: Option<String>,
+    /// When the response was stored, to find the oldest once the map is full.
+    stored_at: i64,
+}
+
+/// URLs `LAST_GOOD` keeps a snapshot for. There is one per upstream URL ever
+/// fetched, which grows with every project, README and essay looked up.
+const MAX_SNAPSHOTS: usize = 1024;
+
+lazy_static::lazy_static! {
+    /// The last successful response per URL, served when the upstream is
+    /// unavailable so an outage degrades to stale content.
+    static ref LAST_GOOD: Mutex<HashMap<String, Snapshot>> = Mutex::new(HashMap::new());
+    /// Every post with its markdown and HTML, shared by the feeds, the sitemap
+    /// and project pages.
+    static ref POSTS_WITH_CONTENT: TtlCache<(), Vec<Post>> = TtlCache::new(POSTS_TTL);
+    static ref GITHUB_RATE_LIMITS: Mutex<HashMap<String, RateLimit>> = Mutex::new(HashMap::new());
+    static ref PROJECT_CATALOGUE: TtlCache<(), Vec<Project>> =
+        TtlCache::new(PROJECT_CATALOGUE_TTL);
+    /// Covers by repository URL.
+    static ref PROJECT_COVERS: TtlCache<String, Option<String>> =
+        TtlCache::new(PROJECT_COVER_TTL);
+    /// Commit and star series by repository URL.
+    static ref PROJECT_ACTIVITY: TtlCache<String, ProjectActivity> =
+        TtlCache::new(PROJECT_ACTIVITY_TTL);
+    static ref REPOSITORY_ACTIVITY: TtlCache<(), Vec<RepositoryActivity>> =
+        TtlCache::new(PROJECT_CATALOGUE_TTL);
+}
+
+/// Loading every essay's markdown costs a request per essay, so the full
+/// set is refreshed at most this often.
+const POSTS_TTL: i64 = 600;
+
+static MISSING_TOKEN_WARNING: Once = Once::new();
+
+pub fn log_warning(message: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tracing::warn!("{message}");
+    #[cfg(target_arch = "wasm32")]
+    worker::console_log!("{}", message);
+}
+
+/// Adds `If-None-Match` when we hold an ETag for the URL, so an unchanged
+/// resource comes back as an empty 304.
+fn conditional(request: HttpRequest) -> HttpRequest {
+    let etag = LAST_GOOD
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&request.url)
+        .and_then(|snapshot| snapshot.etag.clone());
+    match etag {
+        Some(etag) => request.header("If-None-Match", etag),
+        None => request,
     }
-    resp.text().await.ok()
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-async fn http_get_text(url: &str) -> Option<String> {
-    let client = reqwest::Client::builder().build().ok()?;
-    let resp = client
-        .get(url)
-        .header("User-Agent", "elcharitas-wtf")
-        .send()
-        .await
-        .ok()?;
-    if !resp.status().is_success() {
-        return None;
+/// Turns a response into a body while keeping the snapshot in step: a 2xx
+/// replaces it, a 304 reuses it, a 404 drops it and any other failure falls
+/// back to it. A 202 is GitHub still computing statistics, so it falls back
+/// too rather than replacing the snapshot with an empty body.
+fn settle(url: &str, response: Result<HttpResponse, GitHubError>) -> Result<String, GitHubError> {
+    let mut last_good = LAST_GOOD.lock().unwrap_or_else(PoisonError::into_inner);
+    let stale = |error: GitHubError| {
+        last_good
+            .get(url)
+            .map(|snapshot| snapshot.body.clone())
+            .ok_or(error)
+    };
+    match response {
+        Ok(response) if matches!(response.status, 202 | 304) => {
+            stale(GitHubError::Status(response.status))
+        }
+        Ok(response) if response.is_success() => {
+            let snapshot = Snapshot {
+                etag: response.header("ETag").map(str::to_string),
+                body: response.body,
+                stored_at: Utc::now().timestamp(),
+            };
+            let body = snapshot.body.clone();
+            if last_good.len() >= MAX_SNAPSHOTS && !last_good.contains_key(url) {
+                let oldest = last_good
+                    .iter()
+                    .min_by_key(|(_, snapshot)| snapshot.stored_at)
+                    .map(|(url, _)| url.clone());
+                if let Some(oldest) = oldest {
+                    last_good.remove(&oldest);
+                }
+            }
+            last_good.insert(url.to_string(), snapshot);
+            Ok(body)
+        }
+        Ok(response) if response.status == 404 => {
+            last_good.remove(url);
+            Err(GitHubError::Status(404))
+        }
+        Ok(response) => stale(GitHubError::Status(response.status)),
+        Err(error) => stale(error),
     }
-    resp.text().await.ok()
 }
 
-pub async fn fetch_all_posts() -> Vec<Post> {
+async fn http_get_text(url: &str) -> Result<String, GitHubError> {
+    let response = http::client()
+        .send(conditional(
+            HttpRequest::get(url).header("User-Agent", "elcharitas-wtf"),
+        ))
+        .await
+        .map_err(GitHubError::from);
+    settle(url, response)
+}
+
+pub async fn fetch_all_posts() -> Result<Vec<Post>, GitHubError> {
     let url = "https://raw.githubusercontent.com/elcharitas/elcharitas.wtf/main/blog/posts.json";
-    let json = http_get_text(url).await.unwrap_or_default();
-    serde_json::from_str::<Vec<PostMeta>>(&json)
-        .unwrap_or_default()
+    let json = http_get_text(url).await?;
+    Ok(serde_json::from_str::<Vec<PostMeta>>(&json)?
         .into_iter()
         .map(post_meta_to_post)
-        .collect()
+        .collect())
 }
 
 fn strip_frontmatter(markdown: &str) -> &str {
-    if markdown.starts_with("---") {
-        if let Some(end) = markdown[3..].find("\n---") {
-            return markdown[3 + end + 4..].trim_start();
+    if let Some(rest) = markdown.strip_prefix("---") {
+        if let Some(end) = rest.find("\n---") {
+            return rest[end + 4..].trim_start();
         }
     }
     markdown
@@ -110,6 +209,22 @@ fn parse_title_from_frontmatter(markdown: &str) -> Option<String> {
     parse_frontmatter_field(markdown, "title").map(|s| s.to_string())
 }
 
+fn parse_tags_from_frontmatter(markdown: &str) -> Vec<Tag> {
+    parse_frontmatter_field(markdown, "tags")
+        .map(|tags| {
+            tags.split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(|tag| Tag {
+                    id: tag.to_string(),
+                    name: tag.to_string(),
+                    slug: tag.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 pub fn parse_post_from_markdown(slug: &str, raw: &str) -> Post {
     let title = parse_title_from_frontmatter(raw).unwrap_or_else(|| {
         let body = strip_frontmatter(raw);
@@ -122,10 +237,9 @@ pub fn parse_post_from_markdown(slug: &str, raw: &str) -> Post {
     let brief = body
         .lines()
         .map(|l| l.trim())
-        .filter(|l| {
+        .find(|l| {
             !l.is_empty() && !l.starts_with('#') && !l.starts_with("```") && !l.starts_with('!')
         })
-        .next()
         .unwrap_or("")
         .chars()
         .take(200)
@@ -148,130 +262,1436 @@ pub fn parse_post_from_markdown(slug: &str, raw: &str) -> Post {
         response_count: None,
         views: None,
         seo: None,
-        cover_image: None,
+        cover_image: parse_frontmatter_field(raw, "cover").map(|url| PostCoverImage {
+            url: Some(url.to_string()),
+        }),
         author: None,
         content: Some(Content {
             markdown: body.to_string(),
             html: String::new(),
         }),
         og_meta_data: None,
-        tags: Vec::new(),
+        tags: parse_tags_from_frontmatter(raw),
         comments: None,
     }
 }
 
-pub async fn fetch_post_by_slug_from_github(slug: &str) -> Option<Post> {
+/// Folds the post index entry into a post parsed from markdown. The markdown
+/// files carry no dates, and the index category leads the frontmatter tags.
+fn merge_post_meta(post: &mut Post, meta: Post) {
+    post.published_at = meta.published_at;
+    if post.cover_image_url().is_none() {
+        post.cover_image = meta.cover_image;
+    }
+    let mut tags = meta.tags;
+    for tag in std::mem::take(&mut post.tags) {
+        if !tags.iter().any(|t| t.slug == tag.slug) {
+            tags.push(tag);
+        }
+    }
+    post.tags = tags;
+}
+
+async fn fetch_post_markdown(slug: &str) -> Result<Option<String>, GitHubError> {
     let url = format!(
         "https://raw.githubusercontent.com/elcharitas/elcharitas.wtf/main/blog/{}.md",
         slug
     );
-    let raw = http_get_text(&url).await?;
-    Some(parse_post_from_markdown(slug, &raw))
+    match http_get_text(&url).await {
+        Ok(raw) => Ok(Some(raw)),
+        Err(error) if error.is_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
 }
 
-// ---- GitHub projects API ----
+pub async fn fetch_post_by_slug_from_github(slug: &str) -> Result<Option<Post>, GitHubError> {
+    let Some(raw) = fetch_post_markdown(slug).await? else {
+        return Ok(None);
+    };
+    let mut post = parse_post_from_markdown(slug, &raw);
 
-#[derive(Debug)]
-pub enum GitHubError {
-    RequestError(reqwest::Error),
-    JsonError(reqwest::Error),
+    // The index only adds dates and the category, so the essay still renders
+    // when it is unreachable.
+    if let Some(meta) = fetch_all_posts()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .find(|p| p.slug == slug)
+    {
+        merge_post_meta(&mut post, meta);
+    }
+
+    Ok(Some(post))
 }
 
-impl std::fmt::Display for GitHubError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            GitHubError::RequestError(e) => write!(f, "Request error: {}", e),
-            GitHubError::JsonError(e) => write!(f, "JSON parsing error: {}", e),
-        }
-    }
+/// Loads the post index along with every post's markdown and rendered HTML,
+/// for consumers such as feeds that need full content rather than briefs.
+/// Posts whose markdown cannot be fetched keep their index summary.
+pub async fn fetch_all_posts_with_content() -> Result<Vec<Post>, GitHubError> {
+    POSTS_WITH_CONTENT
+        .get_or_refresh((), load_all_posts_with_content())
+        .await
 }
 
-impl std::error::Error for GitHubError {}
+async fn load_all_posts_with_content() -> Result<Vec<Post>, GitHubError> {
+    let posts = fetch_all_posts().await?;
+    Ok(
+        futures::future::join_all(posts.into_iter().map(|meta| async move {
+            let Ok(Some(raw)) = fetch_post_markdown(&meta.slug).await else {
+                return meta;
+            };
+            let mut post = parse_post_from_markdown(&meta.slug, &raw);
+            merge_post_meta(&mut post, meta);
+            if let Some(content) = post.content.as_mut() {
+                content.html = render_markdown(&content.markdown);
+            }
+            post
+        }))
+        .await,
+    )
+}
+
+// ---- GitHub projects API ----
 
-impl From<reqwest::Error> for GitHubError {
-    fn from(error: reqwest::Error) -> Self {
-        GitHubError::RequestError(error)
+#[derive(Debug, thiserror::Error)]
+pub enum GitHubError {
+    #[error("Request error: {0}")]
+    RequestError(#[from] HttpError),
+    #[error("JSON parsing error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Base64 decoding error: {0}")]
+    DecodeError(#[from] base64::DecodeError),
+    #[error("Upstream responded with status {0}")]
+    Status(u16),
+    #[error("GitHub rate limit exhausted until {0}")]
+    RateLimited(DateTime<Utc>),
+    #[error("GitHub GraphQL error: {0}")]
+    GraphQl(String),
+}
+
+impl GitHubError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, GitHubError::Status(404))
     }
 }
 
+const GITHUB_LOGIN: &str = "elcharitas";
+const GITHUB_GRAPHQL_URL: &str = "https://api.github.com/graphql";
+const PROJECT_QUERIES: &str = include_str!("queries/projects.graphql");
+const PROJECTS_PER_PAGE: usize = 25;
+/// Repositories per REST listing page, the most GitHub allows.
+const REST_PAGE_SIZE: usize = 100;
+/// GitHub pages one project query may read before returning a short page.
+const MAX_REST_PAGES_PER_QUERY: u32 = 4;
+const PROJECT_CATALOGUE_TTL: i64 = 600;
+/// Commit and star statistics move slowly, and each project costs up to three
+/// requests to refresh.
+const PROJECT_ACTIVITY_TTL: i64 = 6 * 3600;
+const PROJECT_ACTIVITY_CALLS: u32 = 3;
+const WEEK: i64 = 7 * 24 * 3600;
+const PROJECT_COVER_TTL: i64 = 24 * 3600;
+/// Where repositories conventionally keep a logo, most specific first.
+const COVER_PATHS: &[&str] = &[
+    "static/logo-light.svg",
+    "static/logo.svg",
+    "assets/logo.svg",
+    "assets/logo.png",
+    "docs/logo.svg",
+    "docs/logo.png",
+    ".github/logo.svg",
+    ".github/logo.png",
+    "logo.svg",
+    "logo.png",
+];
+
 #[derive(Debug, Deserialize)]
 struct GitHubRepo {
     name: String,
     description: Option<String>,
     stargazers_count: f32,
     fork: bool,
+    created_at: String,
     pushed_at: String,
     homepage: Option<String>,
     html_url: String,
-    default_branch: String,
-    owner: GitHubOwner,
     topics: Vec<String>,
-    watchers_count: f32,
+    language: Option<String>,
+    /// Includes open pull requests, unlike the GraphQL issue count.
+    open_issues_count: u32,
+    license: Option<GitHubLicense>,
 }
 
 #[derive(Debug, Deserialize)]
-struct GitHubOwner {
-    login: String,
+#[serde(rename_all = "camelCase")]
+struct GitHubLicense {
+    #[serde(alias = "spdx_id")]
+    spdx_id: Option<String>,
+}
+
+impl GitHubLicense {
+    /// GitHub reports unrecognised licenses as `NOASSERTION`.
+    fn spdx(self) -> Option<String> {
+        self.spdx_id.filter(|id| id != "NOASSERTION")
+    }
 }
 
-pub async fn get_all_projects(page: u32) -> Result<Vec<Project>, GitHubError> {
-    let github_token = get_env("GITHUB_TOKEN");
-    let client = reqwest::Client::builder().build()?;
+#[derive(Debug, Deserialize)]
+struct CommitWeek {
+    total: u32,
+}
 
-    let url = format!(
-        "https://api.github.com/user/repos?sort=updated&visibility=public&affiliation=owner,collaborator&per_page=25&direction=desc&page={}",
-        page
+#[derive(Debug, Deserialize)]
+struct Stargazer {
+    starred_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubContent {
+    path: String,
+    content: String,
+    html_url: String,
+    download_url: Option<String>,
+}
+
+/// A repository README along with where its relative links point.
+pub struct Readme {
+    pub markdown: String,
+    /// Location within the repository, e.g. `docs/README.md`.
+    pub path: String,
+    /// The README's page on GitHub; relative links resolve against it.
+    pub html_url: String,
+    /// The raw file; relative images resolve against it.
+    pub download_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectsData {
+    user: Option<GraphQlUser>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectData {
+    repository: Option<GraphQlRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlUser {
+    pinned_items: Nodes<GraphQlRepo>,
+    repositories: Connection<GraphQlRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+    page_info: PageInfo,
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlRepo {
+    name: String,
+    description: Option<String>,
+    url: String,
+    homepage_url: Option<String>,
+    stargazer_count: u32,
+    pushed_at: Option<String>,
+    default_branch_ref: Option<BranchRef>,
+    repository_topics: Nodes<TopicNode>,
+    primary_language: Option<GraphQlLanguage>,
+    languages: Option<LanguageConnection>,
+    releases: TotalCount,
+    issues: TotalCount,
+    license_info: Option<GitHubLicense>,
+    open_graph_image_url: String,
+    uses_custom_open_graph_image: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryActivityData {
+    user: Option<RepositoryActivityUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryActivityUser {
+    repositories: Connection<GraphQlActivityRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlActivityRepo {
+    name: String,
+    description: Option<String>,
+    url: String,
+    created_at: String,
+    stargazer_count: u32,
+    releases: Nodes<GraphQlRelease>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlRelease {
+    name: Option<String>,
+    tag_name: String,
+    url: String,
+    published_at: Option<String>,
+    is_draft: bool,
+    is_prerelease: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlManifestRepo {
+    name: String,
+    cargo_toml: Option<Blob>,
+    package_json: Option<Blob>,
+    pyproject_toml: Option<Blob>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestsData {
+    user: Option<ManifestsUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestsUser {
+    repositories: Connection<GraphQlManifestRepo>,
+}
+
+/// `text` is null for binary or oversized files.
+#[derive(Debug, Deserialize)]
+struct Blob {
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchRef {
+    target: Option<CommitTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitTarget {
+    committed_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopicNode {
+    topic: Topic,
+}
+
+#[derive(Debug, Deserialize)]
+struct Topic {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlLanguage {
+    name: String,
+    color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LanguageConnection {
+    total_size: u64,
+    edges: Vec<LanguageEdge>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguageEdge {
+    size: u64,
+    node: GraphQlLanguage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TotalCount {
+    total_count: u32,
+}
+
+/// One of GitHub's quotas as reported by the `X-RateLimit-*` headers of the
+/// most recent API response. REST (`core`) and GraphQL are metered separately.
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimit {
+    pub resource: String,
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimit {
+    fn from_response(response: &HttpResponse) -> Option<Self> {
+        let header = |name| response.header(name)?.trim().parse::<i64>().ok();
+        Some(Self {
+            resource: response
+                .header("X-RateLimit-Resource")
+                .unwrap_or("core")
+                .to_string(),
+            limit: header("X-RateLimit-Limit")? as u32,
+            remaining: header("X-RateLimit-Remaining")? as u32,
+            reset_at: DateTime::from_timestamp(header("X-RateLimit-Reset")?, 0)?,
+        })
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.remaining == 0 && Utc::now() < self.reset_at
+    }
+
+    /// Under a tenth of the quota left, URLs we already hold are served from
+    /// their snapshot until the window resets.
+    fn is_low(&self) -> bool {
+        self.remaining * 10 < self.limit && Utc::now() < self.reset_at
+    }
+}
+
+/// Every quota seen so far, ordered by resource.
+pub fn github_rate_limits() -> Vec<RateLimit> {
+    let mut rate_limits: Vec<RateLimit> = GITHUB_RATE_LIMITS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .values()
+        .cloned()
+        .collect();
+    rate_limits.sort_by(|a, b| a.resource.cmp(&b.resource));
+    rate_limits
+}
+
+fn github_rate_limit(resource: &str) -> Option<RateLimit> {
+    GITHUB_RATE_LIMITS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(resource)
+        .cloned()
+}
+
+fn record_rate_limit(response: &HttpResponse) -> Option<RateLimit> {
+    let rate_limit = RateLimit::from_response(response)?;
+    if rate_limit.is_low() {
+        log_warning(&format!(
+            "GitHub {} rate limit low: {}/{} remaining until {}",
+            rate_limit.resource, rate_limit.remaining, rate_limit.limit, rate_limit.reset_at
+        ));
+    } else {
+        #[cfg(not(target_arch = "wasm32"))]
+        tracing::debug!(
+            "GitHub {} rate limit: {}/{} remaining until {}",
+            rate_limit.resource,
+            rate_limit.remaining,
+            rate_limit.limit,
+            rate_limit.reset_at
+        );
+    }
+    GITHUB_RATE_LIMITS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(rate_limit.resource.clone(), rate_limit.clone());
+    Some(rate_limit)
+}
+
+/// Refuses to spend `resource`'s quota when it is exhausted, or when it is
+/// low and `key` already has a snapshot to serve.
+fn check_quota(resource: &str, key: &str) -> Result<(), GitHubError> {
+    let Some(rate_limit) = github_rate_limit(resource) else {
+        return Ok(());
+    };
+    let has_snapshot = LAST_GOOD
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(key);
+    if rate_limit.is_exhausted() || (rate_limit.is_low() && has_snapshot) {
+        return Err(GitHubError::RateLimited(rate_limit.reset_at));
+    }
+    Ok(())
+}
+
+/// Records the quota a response reports and turns a 403/429 on an empty
+/// quota into [`GitHubError::RateLimited`].
+fn track_quota(response: Result<HttpResponse, HttpError>) -> Result<HttpResponse, GitHubError> {
+    let response = response?;
+    match record_rate_limit(&response) {
+        Some(rate_limit) if matches!(response.status, 403 | 429) && rate_limit.remaining == 0 => {
+            log_warning(&format!(
+                "GitHub {} rate limit exhausted until {}",
+                rate_limit.resource, rate_limit.reset_at
+            ));
+            Err(GitHubError::RateLimited(rate_limit.reset_at))
+        }
+        _ => Ok(response),
+    }
+}
+
+fn github_token() -> Option<String> {
+    let token = get_env("GITHUB_TOKEN");
+    if token.is_empty() {
+        MISSING_TOKEN_WARNING.call_once(|| {
+            log_warning(
+                "GITHUB_TOKEN is not set; using the unauthenticated GitHub API (60 requests/hour)",
+            )
+        });
+        return None;
+    }
+    Some(token)
+}
+
+async fn github_get_text(url: &str) -> Result<String, GitHubError> {
+    github_get_media(url, "application/vnd.github+json").await
+}
+
+/// A REST call asking for a specific media type, for endpoints whose extra
+/// fields sit behind one.
+async fn github_get_media(url: &str, accept: &str) -> Result<String, GitHubError> {
+    if let Err(error) = check_quota("core", url) {
+        return settle(url, Err(error));
+    }
+
+    let mut request = HttpRequest::get(url)
+        .header("Accept", accept)
+        .header("User-Agent", "Rust-GitHub-Client");
+    if let Some(token) = github_token() {
+        request = request.header("Authorization", format!("token {}", token));
+    }
+
+    let response = track_quota(http::client().send(conditional(request)).await);
+    settle(url, response)
+}
+
+/// Runs one of the operations in `queries/projects.graphql`. GraphQL needs a
+/// token, so callers fall back to REST without one.
+async fn github_graphql<T: DeserializeOwned>(
+    token: &str,
+    operation: &str,
+    variables: serde_json::Value,
+) -> Result<T, GitHubError> {
+    // Queries are POSTs with no ETag to revalidate, so the snapshot is keyed
+    // by the operation and its variables instead of the URL.
+    let key = format!("graphql:{operation}:{variables}");
+    let response = match check_quota("graphql", &key) {
+        Ok(()) => {
+            let request = HttpRequest::post(GITHUB_GRAPHQL_URL)
+                .header("User-Agent", "Rust-GitHub-Client")
+                .header("Authorization", format!("bearer {token}"))
+                .json(&serde_json::json!({
+                    "query": PROJECT_QUERIES,
+                    "operationName": operation,
+                    "variables": variables,
+                }))
+                .idempotent();
+            track_quota(http::client().send(request).await).and_then(reject_failed_query)
+        }
+        Err(error) => Err(error),
+    };
+
+    let body = settle(&key, response)?;
+    let payload: GraphQlResponse<T> = serde_json::from_str(&body)?;
+    payload
+        .data
+        .ok_or_else(|| GitHubError::GraphQl(graphql_messages(&payload.errors)))
+}
+
+/// GraphQL reports a failed query with a 200 and no `data`; that must not
+/// replace the last good snapshot. Partial data, like a `null` repository
+/// for an unknown name, is still a usable answer.
+fn reject_failed_query(response: HttpResponse) -> Result<HttpResponse, GitHubError> {
+    if !response.is_success() {
+        return Ok(response);
+    }
+    let payload: GraphQlResponse<serde_json::Value> = serde_json::from_str(&response.body)?;
+    match payload.data {
+        Some(data) if !data.is_null() => Ok(response),
+        _ => Err(GitHubError::GraphQl(graphql_messages(&payload.errors))),
+    }
+}
+
+fn graphql_messages(errors: &[GraphQlError]) -> String {
+    errors
+        .iter()
+        .map(|error| error.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A page of `query_projects` results.
+pub struct ProjectPage {
+    pub projects: Vec<Project>,
+    /// Where the next page starts; `None` on the last page.
+    pub next: Option<ProjectCursor>,
+}
+
+/// A position in a filtered listing: the GitHub page it is on, and how many
+/// matching projects of that page come before it. With a token the whole
+/// catalogue is one page. Sent to the browser as an opaque string.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProjectCursor {
+    page: u32,
+    skip: usize,
+}
+
+impl ProjectCursor {
+    pub fn parse(cursor: &str) -> Option<Self> {
+        use base64::Engine;
+
+        let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()?;
+        let (page, skip) = std::str::from_utf8(&decoded).ok()?.split_once('.')?;
+        Some(Self {
+            page: page.parse().ok()?,
+            skip: skip.parse().ok()?,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        use base64::Engine;
+
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(format!("{}.{}", self.page, self.skip))
+    }
+}
+
+/// Public, non-fork projects with at least one star, merged with
+/// `projects.json` and filtered by `query`, `PROJECTS_PER_PAGE` matches at a
+/// time from `cursor` on. Without a token GitHub pages the listing, so pages
+/// are read until enough projects match, and sorting by stars or activity
+/// only reaches within each GitHub page.
+pub async fn query_projects(
+    query: &ProjectQuery,
+    cursor: ProjectCursor,
+) -> Result<ProjectPage, GitHubError> {
+    let overlay = load_overlay();
+    let page = match github_token() {
+        Some(token) => {
+            let mut projects = overlay.apply(get_project_catalogue(&token).await?);
+            projects.retain(|project| query.matches(project));
+            let projects = sort_projects(projects, query.sort).await;
+            let end = cursor.skip + PROJECTS_PER_PAGE;
+            ProjectPage {
+                next: (end < projects.len()).then_some(ProjectCursor { page: 1, skip: end }),
+                projects: projects
+                    .into_iter()
+                    .skip(cursor.skip)
+                    .take(PROJECTS_PER_PAGE)
+                    .collect(),
+            }
+        }
+        None => query_rest_projects(query, cursor).await?,
+    };
+    Ok(ProjectPage {
+        projects: with_covers(page.projects).await,
+        ..page
+    })
+}
+
+async fn query_rest_projects(
+    query: &ProjectQuery,
+    cursor: ProjectCursor,
+) -> Result<ProjectPage, GitHubError> {
+    let overlay = load_overlay();
+    let mut projects = Vec::new();
+    let mut position = ProjectCursor {
+        page: cursor.page.max(1),
+        ..cursor
+    };
+    // Sparse filters could walk every page in one request; stop after a few
+    // and let the next request carry on.
+    for _ in 0..MAX_REST_PAGES_PER_QUERY {
+        let (mut batch, more) = get_rest_projects(position.page, query.sort).await?;
+        if position.page == 1 {
+            let included = included_projects(&batch).await;
+            batch.extend(included);
+        }
+        let mut batch = overlay.apply(batch);
+        batch.retain(|project| query.matches(project));
+        let batch = sort_projects(batch, query.sort).await;
+
+        // One project past the page shows whether another page follows, so a
+        // listing that ends on a full page gets no cursor to an empty one.
+        let wanted = PROJECTS_PER_PAGE + 1 - projects.len();
+        let taken = batch.len().saturating_sub(position.skip).min(wanted);
+        projects.extend(batch.into_iter().skip(position.skip).take(taken));
+        position.skip += taken;
+        if projects.len() > PROJECTS_PER_PAGE {
+            projects.truncate(PROJECTS_PER_PAGE);
+            position.skip -= 1;
+            return Ok(ProjectPage {
+                projects,
+                next: Some(position),
+            });
+        }
+        if !more {
+            return Ok(ProjectPage {
+                projects,
+                next: None,
+            });
+        }
+        position = ProjectCursor {
+            page: position.page + 1,
+            skip: 0,
+        };
+    }
+    Ok(ProjectPage {
+        projects,
+        next: Some(position),
+    })
+}
+
+async fn sort_projects(mut projects: Vec<Project>, sort: ProjectSort) -> Vec<Project> {
+    match sort {
+        ProjectSort::Stars => load_overlay().rank(&mut projects),
+        ProjectSort::Updated => projects.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+        ProjectSort::Name => projects.sort_by_key(|project| project.name.to_lowercase()),
+        ProjectSort::Activity => return by_activity(with_activity(projects).await),
+    }
+    projects
+}
+
+/// How often each tag, language and group occurs across the listing, most
+/// common first, for filter pills. Without a token only the first GitHub
+/// page is counted.
+#[derive(Debug, Default)]
+pub struct ProjectFacets {
+    pub tags: Vec<(String, usize)>,
+    pub languages: Vec<(String, usize)>,
+    pub groups: Vec<(String, usize)>,
+}
+
+pub async fn get_project_facets() -> Result<ProjectFacets, GitHubError> {
+    let projects = match github_token() {
+        Some(token) => get_project_catalogue(&token).await?,
+        None => {
+            let (mut projects, _) = get_rest_projects(1, ProjectSort::default()).await?;
+            let included = included_projects(&projects).await;
+            projects.extend(included);
+            projects
+        }
+    };
+    let projects = load_overlay().apply(projects);
+    Ok(ProjectFacets {
+        tags: count_facets(
+            projects
+                .iter()
+                .flat_map(|project| &project.tags)
+                .map(String::as_str),
+        ),
+        languages: count_facets(projects.iter().flat_map(Project::stack)),
+        groups: count_facets(
+            projects
+                .iter()
+                .filter_map(|project| project.group.as_deref()),
+        ),
+    })
+}
+
+/// Repositories `projects.json` includes that `listed` lacks. Ones outside
+/// GitHub, or that GitHub can't be asked about right now, are described by
+/// their entry alone.
+async fn included_projects(listed: &[Project]) -> Vec<Project> {
+    let overlay = load_overlay();
+    let entries: Vec<ProjectOverride> = overlay
+        .includes()
+        .filter(|entry| !listed.iter().any(|project| entry.matches(&project.url)))
+        .cloned()
+        .collect();
+    futures::stream::iter(entries)
+        .map(|entry| async move {
+            let Some((owner, name)) = github_repo(&entry.url) else {
+                return Some(entry.to_project());
+            };
+            match fetch_project(owner, name).await {
+                Ok(Some(project)) => Some(project),
+                Ok(None) => {
+                    log_warning(&format!(
+                        "projects.json includes {}, which GitHub doesn't know",
+                        entry.url
+                    ));
+                    None
+                }
+                Err(_) => Some(entry.to_project()),
+            }
+        })
+        .buffered(4)
+        .filter_map(|project| async move { project })
+        .collect()
+        .await
+}
+
+/// The `(owner, name)` of a github.com repository URL.
+fn github_repo(url: &str) -> Option<(&str, &str)> {
+    let path = url
+        .strip_prefix("https://github.com/")?
+        .trim_end_matches('/');
+    let (owner, name) = path.split_once('/')?;
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    (!owner.is_empty() && !name.is_empty() && !name.contains('/')).then_some((owner, name))
+}
+
+/// Most recent commits first, then most recently pushed.
+fn by_activity(mut projects: Vec<Project>) -> Vec<Project> {
+    projects.sort_by(|a, b| {
+        b.activity
+            .recent_commits()
+            .cmp(&a.activity.recent_commits())
+            .then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
+    projects
+}
+
+/// Fills in each project's weekly commits and star history, four projects at
+/// a time. Statistics only decorate a listing, so a project whose numbers are
+/// unavailable keeps empty series instead of failing the page.
+///
+/// Without a token a listing's statistics would cost more than the hourly
+/// quota, so they are only refreshed while at least half of it remains, and
+/// cached series are served otherwise.
+pub async fn with_activity(projects: Vec<Project>) -> Vec<Project> {
+    futures::stream::iter(projects)
+        .map(|mut project| async move {
+            project.activity = get_project_activity(&project).await;
+            project
+        })
+        .buffered(4)
+        .collect()
+        .await
+}
+
+async fn get_project_activity(project: &Project) -> ProjectActivity {
+    let Some((owner, name)) = github_repo(&project.url) else {
+        return ProjectActivity::default();
+    };
+    if let Some(activity) = PROJECT_ACTIVITY.fresh(&project.url) {
+        return activity;
+    }
+    if github_token().is_none() && !can_spare_for_activity() {
+        return PROJECT_ACTIVITY.stale(&project.url).unwrap_or_default();
+    }
+
+    let now = Utc::now().timestamp();
+    let (commits, stars) = futures::join!(
+        weekly_commits(owner, name),
+        star_history(owner, name, project.stargazers_count as u32, now)
     );
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("token {}", github_token))
-        .header("User-Agent", "Rust-GitHub-Client")
-        .send()
-        .await?;
+    let refreshed = commits.is_ok() && stars.is_ok();
+    // Keep the expired series for whichever half failed.
+    let stale = PROJECT_ACTIVITY.stale(&project.url).unwrap_or_default();
+    let activity = ProjectActivity {
+        commits: commits.unwrap_or(stale.commits),
+        stars: stars.unwrap_or(stale.stars),
+    };
+    if refreshed {
+        PROJECT_ACTIVITY.insert(project.url.clone(), activity.clone());
+    }
+    activity
+}
 
-    if !response.status().is_success() {
+/// Whether the REST quota has room for one project's statistics while keeping
+/// half of it for the listings and pages themselves. A quota not seen yet
+/// counts as having no room.
+fn can_spare_for_activity() -> bool {
+    github_rate_limit("core").is_some_and(|rate_limit| {
+        Utc::now() >= rate_limit.reset_at
+            || rate_limit.remaining >= rate_limit.limit / 2 + PROJECT_ACTIVITY_CALLS
+    })
+}
+
+/// Commits to the default branch in each of the last `ACTIVITY_WEEKS` weeks.
+async fn weekly_commits(owner: &str, name: &str) -> Result<Vec<u32>, GitHubError> {
+    let url = format!("https://api.github.com/repos/{owner}/{name}/stats/commit_activity");
+    let body = github_get_text(&url).await?;
+    // An empty repository answers 204 with no body.
+    if body.trim().is_empty() {
         return Ok(Vec::new());
     }
+    let weeks: Vec<CommitWeek> = serde_json::from_str(&body)?;
+    let skip = weeks.len().saturating_sub(ACTIVITY_WEEKS);
+    Ok(weeks
+        .into_iter()
+        .skip(skip)
+        .map(|week| week.total)
+        .collect())
+}
+
+/// Stargazers at the end of each of the last `ACTIVITY_WEEKS` weeks, counted
+/// back from `total`. Stargazers are listed oldest first, so only the last
+/// two pages are read; weeks before the earliest star they hold are left out
+/// when there are more stars than that.
+async fn star_history(
+    owner: &str,
+    name: &str,
+    total: u32,
+    now: i64,
+) -> Result<Vec<u32>, GitHubError> {
+    const PER_PAGE: u32 = 100;
+    if total == 0 {
+        return Ok(Vec::new());
+    }
+    let last_page = total.div_ceil(PER_PAGE);
+    let mut starred_at = Vec::new();
+    for page in last_page.saturating_sub(1).max(1)..=last_page {
+        let url = format!(
+            "https://api.github.com/repos/{owner}/{name}/stargazers?per_page={PER_PAGE}&page={page}"
+        );
+        let body = github_get_media(&url, "application/vnd.github.star+json").await?;
+        let stargazers: Vec<Stargazer> = serde_json::from_str(&body)?;
+        starred_at.extend(stargazers.iter().filter_map(|stargazer| {
+            DateTime::parse_from_rfc3339(&stargazer.starred_at)
+                .ok()
+                .map(|at| at.timestamp())
+        }));
+    }
+
+    let complete = last_page <= 2;
+    let earliest = starred_at.iter().min().copied().unwrap_or(now);
+    Ok((0..ACTIVITY_WEEKS as i64)
+        .rev()
+        .map(|weeks_ago| now - weeks_ago * WEEK)
+        .filter(|end| complete || *end >= earliest)
+        .map(|end| {
+            let since = starred_at.iter().filter(|at| **at > end).count() as u32;
+            total.saturating_sub(since)
+        })
+        .collect())
+}
+
+async fn get_project_catalogue(token: &str) -> Result<Vec<Project>, GitHubError> {
+    PROJECT_CATALOGUE
+        .get_or_refresh((), fetch_project_catalogue(token))
+        .await
+}
+
+/// Fetches pinned and owned repositories with everything the project cards
+/// show, a hundred repositories per round trip.
+async fn fetch_project_catalogue(token: &str) -> Result<Vec<Project>, GitHubError> {
+    let mut pinned = Vec::new();
+    let mut repos = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let data: ProjectsData = github_graphql(
+            token,
+            "Projects",
+            serde_json::json!({ "login": GITHUB_LOGIN, "after": after }),
+        )
+        .await?;
+        let user = data.user.ok_or(GitHubError::Status(404))?;
+        if after.is_none() {
+            pinned = user.pinned_items.nodes;
+        }
+        repos.extend(user.repositories.nodes);
+        match user.repositories.page_info {
+            PageInfo {
+                has_next_page: true,
+                end_cursor: Some(cursor),
+            } => after = Some(cursor),
+            _ => break,
+        }
+    }
+
+    let mut projects: Vec<Project> = pinned
+        .into_iter()
+        .map(|repo| Project {
+            pinned: true,
+            ..graphql_repo_to_project(repo)
+        })
+        .collect();
+    repos.retain(|repo| {
+        repo.stargazer_count > 0 && !projects.iter().any(|project| project.url == repo.url)
+    });
+    repos.sort_by(|a, b| {
+        b.stargazer_count
+            .cmp(&a.stargazer_count)
+            .then_with(|| b.pushed_at.cmp(&a.pushed_at))
+    });
+    projects.extend(repos.into_iter().map(graphql_repo_to_project));
+    let included = included_projects(&projects).await;
+    projects.extend(included);
+    Ok(projects)
+}
+
+/// Every project in the unfiltered listing, up to `max_pages` pages.
+pub async fn get_every_project(max_pages: u32) -> Result<Vec<Project>, GitHubError> {
+    let query = ProjectQuery::default();
+    let mut projects = Vec::new();
+    let mut cursor = ProjectCursor::default();
+    for _ in 0..max_pages {
+        let page = query_projects(&query, cursor).await?;
+        projects.extend(page.projects);
+        match page.next {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+    Ok(projects)
+}
 
-    let repos: Vec<GitHubRepo> = response.json().await.map_err(GitHubError::JsonError)?;
+/// A repository as the activity timeline sees it: when it was created, and
+/// what it has released since.
+#[derive(Debug, Clone)]
+pub struct RepositoryActivity {
+    pub name: String,
+    pub description: Option<String>,
+    pub url: String,
+    /// RFC 3339, as GitHub reports it.
+    pub created_at: String,
+    /// The latest published releases, newest first, without prereleases.
+    pub releases: Vec<Release>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Release {
+    /// The release's title, or its tag when it has none.
+    pub name: String,
+    pub url: String,
+    pub published_at: String,
+}
+
+/// Our public, non-fork repositories with at least one star, newest first,
+/// less those `projects.json` hides. Without a token only the hundred newest
+/// are listed, and without releases, which would cost a request each.
+pub async fn get_repository_activity() -> Result<Vec<RepositoryActivity>, GitHubError> {
+    REPOSITORY_ACTIVITY
+        .get_or_refresh((), fetch_repository_activity())
+        .await
+}
+
+async fn fetch_repository_activity() -> Result<Vec<RepositoryActivity>, GitHubError> {
+    let overlay = load_overlay();
+    let hidden = |url: &str| overlay.find(url).is_some_and(|entry| entry.hidden);
+    let Some(token) = github_token() else {
+        let url = format!(
+            "https://api.github.com/users/{GITHUB_LOGIN}/repos?type=owner&sort=created&direction=desc&per_page={REST_PAGE_SIZE}"
+        );
+        let repos: Vec<GitHubRepo> = serde_json::from_str(&github_get_text(&url).await?)?;
+        return Ok(repos
+            .into_iter()
+            .filter(|repo| !repo.fork && repo.stargazers_count > 0.0 && !hidden(&repo.html_url))
+            .map(|repo| RepositoryActivity {
+                name: repo.name,
+                description: repo.description,
+                url: repo.html_url,
+                created_at: repo.created_at,
+                releases: Vec::new(),
+            })
+            .collect());
+    };
+
+    let mut repositories = Vec::new();
+    let mut after: Option<String> = None;
+    loop {
+        let data: RepositoryActivityData = github_graphql(
+            &token,
+            "RepositoryActivity",
+            serde_json::json!({ "login": GITHUB_LOGIN, "after": after }),
+        )
+        .await?;
+        let connection = data.user.ok_or(GitHubError::Status(404))?.repositories;
+        repositories.extend(
+            connection
+                .nodes
+                .into_iter()
+                .filter(|repo| repo.stargazer_count > 0 && !hidden(&repo.url))
+                .map(|repo| RepositoryActivity {
+                    releases: repo
+                        .releases
+                        .nodes
+                        .into_iter()
+                        .filter(|release| !release.is_draft && !release.is_prerelease)
+                        .filter_map(|release| {
+                            Some(Release {
+                                name: release
+                                    .name
+                                    .filter(|name| !name.trim().is_empty())
+                                    .unwrap_or(release.tag_name),
+                                url: release.url,
+                                published_at: release.published_at?,
+                            })
+                        })
+                        .collect(),
+                    name: repo.name,
+                    description: repo.description,
+                    url: repo.url,
+                    created_at: repo.created_at,
+                }),
+        );
+        match connection.page_info {
+            PageInfo {
+                has_next_page: true,
+                end_cursor: Some(cursor),
+            } => after = Some(cursor),
+            _ => break,
+        }
+    }
+    Ok(repositories)
+}
+
+/// Dependency manifests at the root of a repository's default branch.
+#[derive(Debug, Clone, Default)]
+pub struct Manifests {
+    pub cargo_toml: Option<String>,
+    pub package_json: Option<String>,
+    pub pyproject_toml: Option<String>,
+}
+
+/// Root manifests for each of `projects`, keyed by project name. With a token
+/// they arrive alongside the repository listing; without one each file is
+/// read from raw.githubusercontent.com, which does not count against the API
+/// quota. Missing or unreadable files are `None`.
+pub async fn get_project_manifests(
+    projects: &[Project],
+) -> Result<HashMap<String, Manifests>, GitHubError> {
+    let Some(token) = github_token() else {
+        let repos = projects.iter().filter_map(|project| {
+            let (owner, name) = github_repo(&project.url)?;
+            let raw_base = format!("https://raw.githubusercontent.com/{owner}/{name}");
+            Some((project.name.clone(), raw_base))
+        });
+        let repos: Vec<(String, String)> = repos.collect();
+        return Ok(futures::stream::iter(repos)
+            .map(|(name, raw_base)| async move {
+                let raw = |file: &str| {
+                    let url = format!("{raw_base}/HEAD/{file}");
+                    async move { http_get_text(&url).await.ok() }
+                };
+                let (cargo_toml, package_json, pyproject_toml) = futures::join!(
+                    raw("Cargo.toml"),
+                    raw("package.json"),
+                    raw("pyproject.toml")
+                );
+                let manifests = Manifests {
+                    cargo_toml,
+                    package_json,
+                    pyproject_toml,
+                };
+                (name, manifests)
+            })
+            .buffer_unordered(4)
+            .collect()
+            .await);
+    };
+
+    let mut manifests = HashMap::new();
+    let mut after: Option<String> = None;
+    loop {
+        let data: ManifestsData = github_graphql(
+            &token,
+            "ProjectManifests",
+            serde_json::json!({ "login": GITHUB_LOGIN, "after": after }),
+        )
+        .await?;
+        let repositories = data.user.ok_or(GitHubError::Status(404))?.repositories;
+        for repo in repositories.nodes {
+            let text = |blob: Option<Blob>| blob.and_then(|blob| blob.text);
+            manifests.insert(
+                repo.name,
+                Manifests {
+                    cargo_toml: text(repo.cargo_toml),
+                    package_json: text(repo.package_json),
+                    pyproject_toml: text(repo.pyproject_toml),
+                },
+            );
+        }
+        match repositories.page_info {
+            PageInfo {
+                has_next_page: true,
+                end_cursor: Some(cursor),
+            } => after = Some(cursor),
+            _ => break,
+        }
+    }
+    // Re-key by project name, which `projects.json` may have changed.
+    Ok(projects
+        .iter()
+        .filter_map(|project| {
+            let (_, repo) = github_repo(&project.url)?;
+            Some((project.name.clone(), manifests.remove(repo)?))
+        })
+        .collect())
+}
+
+/// One page of the public repository listing, without forks and unstarred
+/// repositories, and whether GitHub has more pages. GitHub can order by name
+/// or push date; stars are ordered within the page.
+async fn get_rest_projects(
+    page: u32,
+    sort: ProjectSort,
+) -> Result<(Vec<Project>, bool), GitHubError> {
+    let order = match sort {
+        ProjectSort::Name => "sort=full_name&direction=asc",
+        _ => "sort=pushed&direction=desc",
+    };
+    // `/user/repos` needs a token; without one fall back to the public listing.
+    let url = format!(
+        "https://api.github.com/users/{GITHUB_LOGIN}/repos?type=owner&{order}&per_page={REST_PAGE_SIZE}&page={page}"
+    );
+    let repos: Vec<GitHubRepo> = serde_json::from_str(&github_get_text(&url).await?)?;
+    let more = repos.len() == REST_PAGE_SIZE;
 
     let mut filtered_repos: Vec<GitHubRepo> = repos
         .into_iter()
         .filter(|repo| !repo.fork && repo.stargazers_count > 0.0)
         .collect();
 
-    filtered_repos.sort_by(|a, b| {
-        (b.stargazers_count as u32)
-            .cmp(&(a.stargazers_count as u32))
-            .then_with(|| b.pushed_at.cmp(&a.pushed_at))
-    });
+    if sort == ProjectSort::Stars {
+        filtered_repos.sort_by(|a, b| {
+            (b.stargazers_count as u32)
+                .cmp(&(a.stargazers_count as u32))
+                .then_with(|| b.pushed_at.cmp(&a.pushed_at))
+        });
+    }
+
+    let projects: Vec<Project> = filtered_repos.into_iter().map(repo_to_project).collect();
+
+    Ok((projects, more))
+}
 
-    let projects: Vec<Project> = filtered_repos
+/// The project listed as `name`, with `projects.json` applied. Names the
+/// overlay doesn't know are looked up among our own repositories.
+pub async fn get_project(name: &str) -> Result<Option<Project>, GitHubError> {
+    let overlay = load_overlay();
+    if overlay.named(name).is_some_and(|entry| entry.hidden) {
+        return Ok(None);
+    }
+    // The cached catalogue usually has it, which saves a query per view.
+    if let Some(token) = github_token() {
+        if let Ok(catalogue) = get_project_catalogue(&token).await {
+            let listed = overlay
+                .apply(catalogue)
+                .into_iter()
+                .find(|project| project.name.eq_ignore_ascii_case(name));
+            if let Some(project) = listed {
+                return Ok(with_covers(vec![project]).await.pop());
+            }
+        }
+    }
+
+    let project = match overlay.named(name) {
+        Some(entry) => match github_repo(&entry.url) {
+            Some((owner, repo)) => fetch_project(owner, repo).await?,
+            None => Some(entry.to_project()),
+        },
+        None => fetch_project(GITHUB_LOGIN, name).await?,
+    };
+    let Some(project) = project.and_then(|project| overlay.apply(vec![project]).pop()) else {
+        return Ok(None);
+    };
+    Ok(with_covers(vec![project]).await.pop())
+}
+
+/// The project listed under `name` as of the last catalogue fetch, with its
+/// cover if one has been found. Never waits on GitHub, so it is `None` until
+/// the catalogue has been fetched once.
+pub fn cached_project(name: &str) -> Option<Project> {
+    let catalogue = PROJECT_CATALOGUE.stale(&())?;
+    let mut project = load_overlay()
+        .apply(catalogue)
         .into_iter()
-        .map(|repo| {
-            let cover_image_url = format!(
-                "https://raw.githubusercontent.com/{}/{}/{}/static/logo-light.svg",
-                repo.owner.login, repo.name, repo.default_branch
-            );
-            Project {
-                url: repo.html_url,
-                name: repo.name,
-                description: repo.description.unwrap_or_default(),
-                stargazers_count: repo.stargazers_count,
-                language: None,
-                updated_at: repo.pushed_at,
-                homepage: repo.homepage.unwrap_or_default(),
-                image: Some(cover_image_url),
-                tags: repo.topics,
-                watching: 100.0
-                    * (if repo.stargazers_count > repo.watchers_count {
-                        repo.watchers_count / (repo.stargazers_count + 0.005)
-                    } else {
-                        repo.stargazers_count / (repo.watchers_count + 0.005)
-                    }),
+        .find(|project| project.name.eq_ignore_ascii_case(name))?;
+    if let Some(cover) = PROJECT_COVERS.stale(&project.url) {
+        project.image = cover;
+    }
+    Some(project)
+}
+
+async fn fetch_project(owner: &str, name: &str) -> Result<Option<Project>, GitHubError> {
+    if let Some(token) = github_token() {
+        let data: ProjectData = github_graphql(
+            &token,
+            "Project",
+            serde_json::json!({ "owner": owner, "name": name }),
+        )
+        .await?;
+        return Ok(data.repository.map(graphql_repo_to_project));
+    }
+
+    let url = format!("https://api.github.com/repos/{owner}/{name}");
+    match github_get_text(&url).await {
+        Ok(body) => Ok(Some(repo_to_project(serde_json::from_str(&body)?))),
+        Err(error) if error.is_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// The README of a project on GitHub; `None` for one elsewhere.
+pub async fn get_project_readme(project: &Project) -> Result<Option<Readme>, GitHubError> {
+    use base64::Engine;
+
+    let Some((owner, name)) = github_repo(&project.url) else {
+        return Ok(None);
+    };
+    let url = format!("https://api.github.com/repos/{owner}/{name}/readme");
+    let content: GitHubContent = match github_get_text(&url).await {
+        Ok(body) => serde_json::from_str(&body)?,
+        Err(error) if error.is_not_found() => return Ok(None),
+        Err(error) => return Err(error),
+    };
+    // The API wraps the base64 payload at 60 columns.
+    let encoded: String = content.content.split_whitespace().collect();
+    let markdown = base64::engine::general_purpose::STANDARD.decode(encoded)?;
+    Ok(Some(Readme {
+        markdown: String::from_utf8_lossy(&markdown).into_owned(),
+        path: content.path,
+        download_url: content
+            .download_url
+            .unwrap_or_else(|| content.html_url.clone()),
+        html_url: content.html_url,
+    }))
+}
+
+/// Fills in each project's image with the first cover that exists: the
+/// social preview, then a logo at one of `COVER_PATHS`. Results are cached for
+/// `PROJECT_COVER_TTL`; a project left without one gets a generated cover
+/// from `Project::cover`.
+async fn with_covers(projects: Vec<Project>) -> Vec<Project> {
+    futures::stream::iter(projects)
+        .map(|mut project| async move {
+            project.image = get_project_cover(&project).await;
+            project
+        })
+        .buffered(4)
+        .collect()
+        .await
+}
+
+async fn get_project_cover(project: &Project) -> Option<String> {
+    if let Some(cover) = PROJECT_COVERS.fresh(&project.url) {
+        return cover;
+    }
+
+    let Some((owner, name)) = github_repo(&project.url) else {
+        return project.image.clone();
+    };
+    let raw_base = format!("https://raw.githubusercontent.com/{owner}/{name}");
+    let candidates = project.image.iter().cloned().chain(
+        COVER_PATHS
+            .iter()
+            .map(|path| format!("{raw_base}/HEAD/{path}")),
+    );
+
+    // Probe one at a time so a repository with a social preview costs a
+    // single request. The first hit wins, but only if every candidate ahead
+    // of it gave a definite answer; otherwise keep what we had until the
+    // probes succeed.
+    let mut cover = None;
+    for url in candidates {
+        match exists(&url).await {
+            Ok(true) => {
+                cover = Some(url);
+                break;
             }
+            Ok(false) => {}
+            Err(_) => return PROJECT_COVERS.stale(&project.url).flatten(),
+        }
+    }
+    PROJECT_COVERS.insert(project.url.clone(), cover.clone());
+    cover
+}
+
+/// Whether `url` answers a HEAD with success; 404 and 403 count as absent.
+async fn exists(url: &str) -> Result<bool, GitHubError> {
+    let response = http::client()
+        .send(HttpRequest::head(url).header("User-Agent", "elcharitas-wtf"))
+        .await?;
+    match response.status {
+        status if (200..300).contains(&status) => Ok(true),
+        403 | 404 => Ok(false),
+        status => Err(GitHubError::Status(status)),
+    }
+}
+
+fn graphql_repo_to_project(repo: GraphQlRepo) -> Project {
+    let branch = repo.default_branch_ref;
+    // GitHub makes up a preview for every repository; only a custom one is
+    // worth showing.
+    let image = repo
+        .uses_custom_open_graph_image
+        .then_some(repo.open_graph_image_url);
+    let languages = repo
+        .languages
+        .filter(|languages| languages.total_size > 0)
+        .map(|languages| {
+            languages
+                .edges
+                .into_iter()
+                .map(|edge| LanguageShare {
+                    name: edge.node.name,
+                    color: edge.node.color,
+                    bytes: edge.size,
+                    percent: 100.0 * edge.size as f32 / languages.total_size as f32,
+                })
+                .collect()
         })
-        .collect();
+        .unwrap_or_default();
+    Project {
+        name: repo.name,
+        description: repo.description.unwrap_or_default(),
+        url: repo.url,
+        image,
+        tags: repo
+            .repository_topics
+            .nodes
+            .into_iter()
+            .map(|node| node.topic.name)
+            .collect(),
+        stargazers_count: repo.stargazer_count as f32,
+        language: repo.primary_language.map(|language| language.name),
+        languages,
+        updated_at: repo.pushed_at.unwrap_or_default(),
+        homepage: repo.homepage_url.unwrap_or_default(),
+        pinned: false,
+        releases: Some(repo.releases.total_count),
+        open_issues: repo.issues.total_count,
+        license: repo.license_info.and_then(GitHubLicense::spdx),
+        last_commit_at: branch
+            .and_then(|branch| branch.target)
+            .and_then(|target| target.committed_date),
+        ..Project::default()
+    }
+}
 
-    Ok(projects)
+fn repo_to_project(repo: GitHubRepo) -> Project {
+    Project {
+        image: None,
+        url: repo.html_url,
+        name: repo.name,
+        description: repo.description.unwrap_or_default(),
+        stargazers_count: repo.stargazers_count,
+        language: repo.language,
+        languages: Vec::new(),
+        updated_at: repo.pushed_at,
+        homepage: repo.homepage.unwrap_or_default(),
+        tags: repo.topics,
+        pinned: false,
+        releases: None,
+        open_issues: repo.open_issues_count,
+        license: repo.license.and_then(GitHubLicense::spdx),
+        last_commit_at: None,
+        ..Project::default()
+    }
 }
@@ -1,5 +1,6 @@
+use crate::publications::PublicationEntry;
+use crate::timeline::{TimelineCategory, TimelineEntry, TimelineSource};
 use axum::extract::Query;
-use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 
 #[cfg(target_arch = "wasm32")]
@@ -26,6 +27,7 @@ pub fn init_env(env: &worker::Env) {
             "ENVIRONMENT",
             "RESEND_API_KEY",
             "NEWSLETTER_SEND_TOKEN",
+            "RSS_ITEM_LIMIT",
         ] {
             let value = env
                 .secret(key)
@@ -67,6 +69,112 @@ pub fn get_env(key: &str) -> String {
     }
 }
 
+pub const BASE_URL: &str = "https://elcharitas.wtf";
+
+/// Lowercases `text` and joins its alphanumeric runs with dashes.
+pub fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Parses the `YYYY-MM-DD` dates used by the post index as well as full
+/// RFC 3339 timestamps, treating bare dates as midnight UTC.
+pub fn parse_date(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|date| date.to_utc())
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|date| date.and_utc())
+        })
+}
+
+/// How often each value occurs, ignoring case, most common first and then
+/// alphabetically; the first spelling seen is kept. Used for filter pills.
+pub fn count_facets<'a>(values: impl IntoIterator<Item = &'a str>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for value in values {
+        match counts
+            .iter_mut()
+            .find(|(seen, _)| seen.eq_ignore_ascii_case(value))
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value.to_string(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Escapes text for an HTML or XML attribute value or text node. momenta
+/// escapes text nodes itself but not attribute values.
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// `url` escaped for an attribute value, or `None` unless it is an absolute
+/// http(s) URL. For links that come from third-party metadata.
+pub fn http_url(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url.trim()).ok()?;
+    matches!(parsed.scheme(), "http" | "https").then(|| escape_xml(url.trim()))
+}
+
+/// Renders essay markdown to HTML with the options the essay pages use.
+pub fn render_markdown(markdown: &str) -> String {
+    markdown_to_html(markdown, true)
+}
+
+/// Renders markdown written by someone else, such as a repository README.
+/// Raw HTML is replaced with a placeholder comment and `javascript:` style
+/// links are dropped, so the output is safe to inline into the page.
+pub fn render_untrusted_markdown(markdown: &str) -> String {
+    markdown_to_html(markdown, false)
+}
+
+fn markdown_to_html(markdown: &str, allow_html: bool) -> String {
+    let options = comrak::Options {
+        render: comrak::RenderOptions {
+            unsafe_: allow_html,
+            ..Default::default()
+        },
+        extension: comrak::ExtensionOptions {
+            table: true,
+            strikethrough: true,
+            autolink: true,
+            tasklist: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // Hashnode exports images as ![alt](url align="left") — strip the
+    // trailing align attribute so comrak parses it as a valid image.
+    let mut cleaned = String::with_capacity(markdown.len());
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("![") {
+            cleaned.push_str(
+                &line
+                    .replace(r#" align="left""#, "")
+                    .replace(r#" align="right""#, "")
+                    .replace(r#" align="center""#, ""),
+            );
+        } else {
+            cleaned.push_str(line);
+        }
+        cleaned.push('\n');
+    }
+
+    comrak::markdown_to_html(&cleaned, &options)
+}
+
 #[derive(Deserialize)]
 pub struct PageParams {
     pub slug: String,
@@ -80,46 +188,19 @@ pub struct PageQuery {
 
 impl PageQuery {
     pub fn from_query(query: Query<serde_json::Value>) -> Self {
-        let datastar: String = query
-            .0
+        serde_json::from_value(Self::signals(&query.0)).unwrap_or_default()
+    }
+
+    /// Every signal datastar sent along with a request.
+    pub fn signals(query: &serde_json::Value) -> serde_json::Value {
+        query
             .get("datastar")
             .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-        serde_json::from_str(&datastar).unwrap_or_default()
+            .and_then(|datastar| serde_json::from_str(datastar).ok())
+            .unwrap_or_default()
     }
 }
 
-pub struct NavigationInfo {
-    pub name: &'static str,
-    pub href: &'static str,
-}
-
-lazy_static! {
-    pub static ref NAVIGATION: Vec<NavigationInfo> = vec![
-        NavigationInfo {
-            name: "Projects",
-            href: "/projects"
-        },
-        NavigationInfo {
-            name: "Essays",
-            href: "/essays"
-        },
-        NavigationInfo {
-            name: "Publications",
-            href: "/publications"
-        },
-        NavigationInfo {
-            name: "Resume",
-            href: "/resume"
-        },
-        NavigationInfo {
-            name: "Timeline",
-            href: "/adventures"
-        },
-    ];
-}
-
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Tag {
     pub id: String,
@@ -144,7 +225,7 @@ pub struct OpenGraphMetaData {
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct SEO {
+pub struct Seo {
     pub title: Option<String>,
     pub description: Option<String>,
 }
@@ -173,7 +254,7 @@ pub struct Post {
     #[serde(rename = "responseCount")]
     pub response_count: Option<i32>,
     pub views: Option<i32>,
-    pub seo: Option<SEO>,
+    pub seo: Option<Seo>,
     #[serde(rename = "coverImage")]
     pub cover_image: Option<PostCoverImage>,
     pub author: Option<serde_json::Value>,
@@ -184,18 +265,480 @@ pub struct Post {
     pub comments: Option<PostCommentConnection>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Post {
+    pub fn published(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.published_at.as_deref().and_then(parse_date)
+    }
+
+    /// Last modification time, falling back to the publish date.
+    pub fn updated(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.updated_at
+            .as_deref()
+            .and_then(parse_date)
+            .or_else(|| self.published())
+    }
+
+    pub fn cover_image_url(&self) -> Option<&str> {
+        self.cover_image
+            .as_ref()
+            .and_then(|cover| cover.url.as_deref())
+    }
+}
+
+/// Guesses an image MIME type from the extension in `url`.
+pub fn image_mime_type(url: &str) -> &'static str {
+    let path = url.split(['?', '#']).next().unwrap_or(url).to_lowercase();
+    match path.rsplit('.').next() {
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        _ => "image/jpeg",
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Project {
     pub name: String,
     pub description: String,
     pub url: String,
+    /// The social preview or a logo from the repository, once checked to
+    /// exist; see [`Project::cover`].
     pub image: Option<String>,
     pub tags: Vec<String>,
     pub stargazers_count: f32,
     pub language: Option<String>,
+    /// Language breakdown by code size, largest first. Empty when the project
+    /// came from the REST API, which only reports the primary language.
+    pub languages: Vec<LanguageShare>,
     pub updated_at: String,
     pub homepage: String,
-    pub watching: f32,
+    pub pinned: bool,
+    /// `None` when the project came from the REST API.
+    pub releases: Option<u32>,
+    pub open_issues: u32,
+    /// SPDX identifier, e.g. `MIT`.
+    pub license: Option<String>,
+    pub last_commit_at: Option<String>,
+    /// Empty until filled in by `requests::with_activity`.
+    #[serde(default)]
+    pub activity: ProjectActivity,
+    /// Set from `projects.json`, like `screenshots`.
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub screenshots: Vec<String>,
+}
+
+impl Project {
+    /// The languages the project is written in, largest first, falling back
+    /// to the primary language when there is no breakdown.
+    pub fn stack(&self) -> Vec<&str> {
+        if self.languages.is_empty() {
+            return self.language.as_deref().into_iter().collect();
+        }
+        self.languages
+            .iter()
+            .map(|language| language.name.as_str())
+            .collect()
+    }
+
+    /// The project's image, else its first screenshot, else an identicon
+    /// generated from its name.
+    pub fn cover(&self) -> String {
+        self.image
+            .iter()
+            .chain(&self.screenshots)
+            .next()
+            .cloned()
+            .unwrap_or_else(|| format!("{BASE_URL}/projects/{}/cover.svg", self.name))
+    }
+}
+
+/// Weeks of history behind a project's activity series.
+pub const ACTIVITY_WEEKS: usize = 26;
+/// Weeks of commits that count as recent when sorting by activity.
+pub const RECENT_WEEKS: usize = 12;
+
+/// Weekly series over the last [`ACTIVITY_WEEKS`] weeks, oldest first. Either
+/// may be shorter, or empty, when GitHub has no statistics for the project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectActivity {
+    /// Commits to the default branch each week.
+    pub commits: Vec<u32>,
+    /// Stargazers at the end of each week.
+    pub stars: Vec<u32>,
+}
+
+impl ProjectActivity {
+    pub fn recent_commits(&self) -> u32 {
+        self.commits.iter().rev().take(RECENT_WEEKS).sum()
+    }
+
+    /// Stars gained over the series, ignoring stars since removed.
+    pub fn stars_gained(&self) -> u32 {
+        match (self.stars.first(), self.stars.last()) {
+            (Some(first), Some(last)) => last.saturating_sub(*first),
+            _ => 0,
+        }
+    }
+}
+
+/// How `/projects` orders its listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ProjectSort {
+    /// Curated and pinned projects first, then by stars.
+    #[default]
+    Stars,
+    /// Most recently pushed first.
+    Updated,
+    Name,
+    /// Most commits in recent weeks first, so maintained work leads.
+    Activity,
+}
+
+impl ProjectSort {
+    pub const ALL: [Self; 4] = [Self::Stars, Self::Updated, Self::Name, Self::Activity];
+
+    pub fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("updated") => Self::Updated,
+            Some("name") => Self::Name,
+            Some("activity") => Self::Activity,
+            _ => Self::Stars,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stars => "stars",
+            Self::Updated => "updated",
+            Self::Name => "name",
+            Self::Activity => "activity",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stars => "Most starred",
+            Self::Updated => "Recently updated",
+            Self::Name => "Name",
+            Self::Activity => "Recently active",
+        }
+    }
+}
+
+/// Filters and order for `/projects`, e.g. `?tag=cli&lang=rust&sort=updated`.
+/// Filters match case-insensitively; empty ones are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectQuery {
+    pub tag: Option<String>,
+    pub language: Option<String>,
+    pub group: Option<String>,
+    /// Matched against names and descriptions.
+    pub search: Option<String>,
+    pub sort: ProjectSort,
+}
+
+impl ProjectQuery {
+    /// Reads the query from URL parameters, or from the same names as
+    /// datastar signals.
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        let param = |name| query_param(params, name);
+        Self {
+            tag: param("tag"),
+            language: param("lang"),
+            group: param("group"),
+            search: param("q"),
+            sort: ProjectSort::parse(param("sort").as_deref()),
+        }
+    }
+
+    pub fn matches(&self, project: &Project) -> bool {
+        let matches = |filter: &Option<String>, values: Vec<&str>| match filter {
+            Some(filter) => values
+                .iter()
+                .any(|value| value.eq_ignore_ascii_case(filter)),
+            None => true,
+        };
+        let text = format!("{} {}", project.name, project.description).to_lowercase();
+        matches(&self.tag, project.tags.iter().map(String::as_str).collect())
+            && matches(&self.language, project.stack())
+            && matches(&self.group, project.group.as_deref().into_iter().collect())
+            && self
+                .search
+                .as_ref()
+                .is_none_or(|search| text.contains(&search.to_lowercase()))
+    }
+
+    /// `/projects` with this query, for links that can be shared.
+    pub fn href(&self) -> String {
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        for (name, value) in [
+            ("tag", &self.tag),
+            ("lang", &self.language),
+            ("group", &self.group),
+            ("q", &self.search),
+        ] {
+            if let Some(value) = value {
+                params.append_pair(name, value);
+            }
+        }
+        if self.sort != ProjectSort::default() {
+            params.append_pair("sort", self.sort.as_str());
+        }
+        match params.finish() {
+            query if query.is_empty() => "/projects".to_string(),
+            query => format!("/projects?{query}"),
+        }
+    }
+}
+
+/// Filters for `/essays`, e.g. `?tag=rust&q=async`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PostQuery {
+    pub tag: Option<String>,
+    /// Matched against titles and briefs.
+    pub search: Option<String>,
+}
+
+impl PostQuery {
+    /// Reads the query from URL parameters, or from the same names as
+    /// datastar signals.
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        Self {
+            tag: query_param(params, "tag"),
+            search: query_param(params, "q"),
+        }
+    }
+
+    pub fn matches(&self, post: &Post) -> bool {
+        let text = format!("{} {}", post.title, post.brief).to_lowercase();
+        self.tag.as_ref().is_none_or(|tag| {
+            post.tags.iter().any(|candidate| {
+                candidate.name.eq_ignore_ascii_case(tag) || candidate.slug.eq_ignore_ascii_case(tag)
+            })
+        }) && self
+            .search
+            .as_ref()
+            .is_none_or(|search| text.contains(&search.to_lowercase()))
+    }
+
+    /// `/essays` with this query at `page`, for links that can be shared.
+    pub fn href(&self, page: usize) -> String {
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        for (name, value) in [("tag", &self.tag), ("q", &self.search)] {
+            if let Some(value) = value {
+                params.append_pair(name, value);
+            }
+        }
+        if page > 1 {
+            params.append_pair("page", &page.to_string());
+        }
+        match params.finish() {
+            query if query.is_empty() => "/essays".to_string(),
+            query => format!("/essays?{query}"),
+        }
+    }
+}
+
+/// How `/publications` sections its listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PublicationGrouping {
+    #[default]
+    Year,
+    Type,
+}
+
+impl PublicationGrouping {
+    pub const ALL: [Self; 2] = [Self::Year, Self::Type];
+
+    pub fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("type") => Self::Type,
+            _ => Self::Year,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Type => "type",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Year => "By year",
+            Self::Type => "By type",
+        }
+    }
+}
+
+/// Filters and grouping for `/publications`, e.g.
+/// `?type=Journal%20Article&year=2023&by=type`. Filters match
+/// case-insensitively; empty ones are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PublicationQuery {
+    pub work_type: Option<String>,
+    pub venue: Option<String>,
+    pub year: Option<String>,
+    pub keyword: Option<String>,
+    /// Matched against titles, venues, authors and keywords.
+    pub search: Option<String>,
+    pub grouping: PublicationGrouping,
+}
+
+impl PublicationQuery {
+    /// Reads the query from URL parameters, or from the same names as
+    /// datastar signals.
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        let param = |name| query_param(params, name);
+        Self {
+            work_type: param("type"),
+            venue: param("venue"),
+            year: param("year"),
+            keyword: param("keyword"),
+            search: param("q"),
+            grouping: PublicationGrouping::parse(param("by").as_deref()),
+        }
+    }
+
+    pub fn matches(&self, entry: &PublicationEntry) -> bool {
+        let matches = |filter: &Option<String>, value: Option<&str>| match filter {
+            Some(filter) => value.is_some_and(|value| value.eq_ignore_ascii_case(filter)),
+            None => true,
+        };
+        let text = format!(
+            "{} {} {} {}",
+            entry.title,
+            entry.venue.as_deref().unwrap_or_default(),
+            entry.authors.join(" "),
+            entry.keywords.join(" ")
+        )
+        .to_lowercase();
+        matches(&self.work_type, entry.work_type.as_deref())
+            && matches(&self.venue, entry.venue.as_deref())
+            && matches(&self.year, entry.year())
+            && self.keyword.as_ref().is_none_or(|keyword| {
+                entry
+                    .keywords
+                    .iter()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(keyword))
+            })
+            && self
+                .search
+                .as_ref()
+                .is_none_or(|search| text.contains(&search.to_lowercase()))
+    }
+
+    /// `/publications` with this query, for links that can be shared.
+    pub fn href(&self) -> String {
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        for (name, value) in [
+            ("type", &self.work_type),
+            ("venue", &self.venue),
+            ("year", &self.year),
+            ("keyword", &self.keyword),
+            ("q", &self.search),
+        ] {
+            if let Some(value) = value {
+                params.append_pair(name, value);
+            }
+        }
+        if self.grouping != PublicationGrouping::default() {
+            params.append_pair("by", self.grouping.as_str());
+        }
+        match params.finish() {
+            query if query.is_empty() => "/publications".to_string(),
+            query => format!("/publications?{query}"),
+        }
+    }
+}
+
+/// Filters for `/adventures`, e.g. `?category=career&year=2023&releases=off`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimelineQuery {
+    pub category: Option<TimelineCategory>,
+    pub year: Option<i32>,
+    /// Sources switched off, in [`TimelineSource::ALL`] order.
+    pub hidden: Vec<TimelineSource>,
+}
+
+impl TimelineQuery {
+    /// Reads the query from URL parameters, or from the same names as
+    /// datastar signals. Unknown categories and years are ignored.
+    pub fn from_params(params: &serde_json::Value) -> Self {
+        Self {
+            category: query_param(params, "category")
+                .and_then(|category| TimelineCategory::parse(&category)),
+            year: query_param(params, "year").and_then(|year| year.parse().ok()),
+            // `false` as a signal, `off` in a URL.
+            hidden: TimelineSource::ALL
+                .into_iter()
+                .filter(|source| match params.get(source.as_str()) {
+                    Some(serde_json::Value::Bool(shown)) => !shown,
+                    Some(serde_json::Value::String(value)) => {
+                        matches!(value.trim(), "off" | "false" | "0")
+                    }
+                    _ => false,
+                })
+                .collect(),
+        }
+    }
+
+    /// The sources left switched on.
+    pub fn sources(&self) -> Vec<TimelineSource> {
+        TimelineSource::ALL
+            .into_iter()
+            .filter(|source| !self.hidden.contains(source))
+            .collect()
+    }
+
+    pub fn matches(&self, entry: &TimelineEntry) -> bool {
+        !self.hidden.contains(&entry.source)
+            && self
+                .category
+                .is_none_or(|category| entry.category == category)
+            && self.year.is_none_or(|year| entry.date.year == year)
+    }
+
+    /// `/adventures` with this query, for links that can be shared.
+    pub fn href(&self) -> String {
+        let mut params = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(category) = self.category {
+            params.append_pair("category", category.as_str());
+        }
+        if let Some(year) = self.year {
+            params.append_pair("year", &year.to_string());
+        }
+        for source in &self.hidden {
+            params.append_pair(source.as_str(), "off");
+        }
+        match params.finish() {
+            query if query.is_empty() => "/adventures".to_string(),
+            query => format!("/adventures?{query}"),
+        }
+    }
+}
+
+/// A trimmed, non-empty string parameter.
+fn query_param(params: &serde_json::Value, name: &str) -> Option<String> {
+    params
+        .get(name)
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageShare {
+    pub name: String,
+    /// GitHub's hex colour for the language.
+    pub color: Option<String>,
+    pub bytes: u64,
+    pub percent: f32,
 }
 
 pub mod xml_elements {
@@ -210,15 +753,22 @@ pub mod xml_elements {
                 loc {}
                 title {}
                 description {}
-                link {}
+                link {
+                    rel: String,
+                    href: String,
+                    type_: String,
+                }
                 lastmod {}
                 changefreq {}
                 priority {}
                 image {}
                 lastBuildDate {}
                 item {}
-                guid {}
+                guid {
+                    isPermaLink: String,
+                }
                 pubDate {}
+                language {}
                 sitemapindex {
                     xmlns: String,
                 }
@@ -227,6 +777,173 @@ pub mod xml_elements {
                     xmlns: String,
                 }
                 url {}
+                feed {
+                    xmlns: String,
+                }
+                entry {}
+                id {}
+                updated {}
+                published {}
+                subtitle {}
+                icon {}
+                author {}
+                name {}
+                uri {}
+                summary {
+                    type_: String,
+                }
+                content {
+                    type_: String,
+                }
+                category {
+                    term: String,
+                    label: String,
+                }
+            }
+        }
+    }
+
+    use momenta::nodes::{Component, Element, Node};
+
+    /// Declares components for prefixed tags such as `content:encoded`, which
+    /// `derive_elements!` can't express since its tag names are identifiers.
+    /// `container` elements take children; `empty` ones only carry attributes.
+    macro_rules! namespaced_elements {
+        ($(
+            $kind:ident $name:ident($props:ident) => $tag:literal {
+                $($attr:ident => $attr_name:literal),* $(,)?
+            }
+        )*) => {
+            $(namespaced_elements!(@$kind $name $props $tag { $($attr => $attr_name),* });)*
+        };
+        (@container $name:ident $props:ident $tag:literal { $($attr:ident => $attr_name:literal),* }) => {
+            pub struct $props {
+                $(pub $attr: String,)*
+                pub children: Vec<Node>,
+            }
+
+            pub struct $name;
+
+            impl Component for $name {
+                type Props = $props;
+
+                fn render(props: &Self::Props) -> Node {
+                    #[allow(unused_mut)]
+                    let mut attributes = Vec::new();
+                    $(
+                        if !props.$attr.is_empty() {
+                            attributes.push(($attr_name.to_string(), props.$attr.clone()));
+                        }
+                    )*
+                    Element::parse_tag_with_attributes("", $tag, attributes, vec![], "", props.children.clone())
+                }
+            }
+        };
+        (@empty $name:ident $props:ident $tag:literal { $($attr:ident => $attr_name:literal),* }) => {
+            pub struct $props {
+                $(pub $attr: String,)*
+            }
+
+            pub struct $name;
+
+            impl Component for $name {
+                type Props = $props;
+
+                fn render(props: &Self::Props) -> Node {
+                    let mut attributes = Vec::new();
+                    $(
+                        if !props.$attr.is_empty() {
+                            attributes.push(($attr_name.to_string(), props.$attr.clone()));
+                        }
+                    )*
+                    Element::parse_tag_with_attributes("", $tag, attributes, vec![], "", Vec::new())
+                }
+            }
+        };
+    }
+
+    namespaced_elements! {
+        container Rss(RssProps) => "rss" {
+            version => "version",
+            xmlns_atom => "xmlns:atom",
+            xmlns_content => "xmlns:content",
+            xmlns_dc => "xmlns:dc",
+            xmlns_media => "xmlns:media",
+        }
+        empty AtomLink(AtomLinkProps) => "atom:link" {
+            href => "href",
+            rel => "rel",
+            type_ => "type",
+        }
+        container ContentEncoded(ContentEncodedProps) => "content:encoded" {}
+        container DcCreator(DcCreatorProps) => "dc:creator" {}
+        empty MediaContent(MediaContentProps) => "media:content" {
+            url => "url",
+            medium => "medium",
+            type_ => "type",
+        }
+        container UrlSet(UrlSetProps) => "urlset" {
+            xmlns => "xmlns",
+            xmlns_image => "xmlns:image",
+        }
+        container ImageImage(ImageImageProps) => "image:image" {}
+        container ImageLoc(ImageLocProps) => "image:loc" {}
+    }
+}
+
+/// SVG tags and attributes momenta's built-in elements lack, such as `<text>`
+/// and dashed strokes, for charts rendered server-side. Bring them in with
+/// `use svg_elements as momenta;` inside a component. momenta never renders
+/// `width` or `height`, so draw bars as `<path>`s rather than `<rect>`s.
+pub mod svg_elements {
+    pub mod dom {
+        pub mod elements {
+            use momenta::dom::derive_elements;
+            extern crate alloc;
+
+            derive_elements! {
+                svg {
+                    xmlns: String,
+                    viewBox: String,
+                    preserveAspectRatio: String,
+                }
+                title {}
+                g {
+                    transform: String,
+                    fill: String,
+                }
+                circle {
+                    cx: String,
+                    cy: String,
+                    r: String,
+                    fill: String,
+                    stroke: String,
+                    stroke_width: String,
+                    stroke_dasharray: String,
+                    stroke_dashoffset: String,
+                    transform: String,
+                }
+                polyline {
+                    points: String,
+                    fill: String,
+                    stroke: String,
+                    stroke_width: String,
+                    stroke_linecap: String,
+                    stroke_linejoin: String,
+                }
+                path {
+                    d: String,
+                    fill: String,
+                    stroke: String,
+                }
+                text {
+                    x: String,
+                    y: String,
+                    fill: String,
+                    font_size: String,
+                    text_anchor: String,
+                    dominant_baseline: String,
+                }
             }
         }
     }
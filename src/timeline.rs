@@ -0,0 +1,342 @@
+//! The timeline behind `/adventures`: dated entries with a category, and
+//! optionally a body, a place, links and images.
+//!
+//! Entries are a JSON array:
+//!
+//! ```json
+//! [{
+//!     "date": "2023-10-14",
+//!     "title": "Spoke at DevFest 2023",
+//!     "body": "On *Supercharging Frontend Performance*.",
+//!     "category": "community",
+//!     "location": "Lagos, Nigeria",
+//!     "links": [{ "label": "Slides", "url": "https://example.com/slides" }],
+//!     "images": [{ "src": "/assets/devfest.jpg", "alt": "On stage" }]
+//! }]
+//! ```
+//!
+//! The original `year -> month -> [markdown]` layout still loads, with each
+//! line becoming a month-precision entry in a category guessed from its text.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A `YYYY`, `YYYY-MM` or `YYYY-MM-DD` date. Less precise dates sort
+/// before the months or days they contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TimelineDate {
+    pub year: i32,
+    pub month: Option<u32>,
+    /// Only set along with the month.
+    pub day: Option<u32>,
+}
+
+impl TimelineDate {
+    /// `Q1` to `Q4`, with the month known.
+    pub fn quarter(self) -> Option<String> {
+        self.month.map(|month| format!("Q{}", (month - 1) / 3 + 1))
+    }
+}
+
+impl TryFrom<String> for TimelineDate {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("invalid date {value:?}, expected YYYY, YYYY-MM or YYYY-MM-DD");
+        let mut parts = value.splitn(3, '-').map(str::parse::<u32>);
+        let year = match parts.next() {
+            Some(Ok(year)) => year as i32,
+            _ => return Err(invalid()),
+        };
+        let month = parts.next().transpose().map_err(|_| invalid())?;
+        let day = parts.next().transpose().map_err(|_| invalid())?;
+        chrono::NaiveDate::from_ymd_opt(year, month.unwrap_or(1), day.unwrap_or(1))
+            .ok_or_else(invalid)?;
+        Ok(Self { year, month, day })
+    }
+}
+
+impl From<TimelineDate> for String {
+    fn from(date: TimelineDate) -> Self {
+        match (date.month, date.day) {
+            (Some(month), Some(day)) => format!("{}-{month:02}-{day:02}", date.year),
+            (Some(month), None) => format!("{}-{month:02}", date.year),
+            _ => date.year.to_string(),
+        }
+    }
+}
+
+/// `2023`, `October 2023`, or `October 14, 2023`.
+impl fmt::Display for TimelineDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(month) = self.month else {
+            return write!(f, "{}", self.year);
+        };
+        let format = match self.day {
+            Some(_) => "%B %-d, %Y",
+            None => "%B %Y",
+        };
+        let date = chrono::NaiveDate::from_ymd_opt(self.year, month, self.day.unwrap_or(1))
+            .unwrap_or_default();
+        write!(f, "{}", date.format(format))
+    }
+}
+
+/// Where an entry comes from: `adventures.json`, or one of the feeds the
+/// timeline merges in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TimelineSource {
+    #[default]
+    Manual,
+    Essays,
+    Repositories,
+    Releases,
+    Publications,
+}
+
+impl TimelineSource {
+    pub const ALL: [Self; 5] = [
+        Self::Manual,
+        Self::Essays,
+        Self::Repositories,
+        Self::Releases,
+        Self::Publications,
+    ];
+
+    /// The URL parameter and signal that switch the source off.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "highlights",
+            Self::Essays => "essays",
+            Self::Repositories => "repos",
+            Self::Releases => "releases",
+            Self::Publications => "publications",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Manual => "Highlights",
+            Self::Essays => "Essays",
+            Self::Repositories => "New repositories",
+            Self::Releases => "Releases",
+            Self::Publications => "Publications",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineCategory {
+    Project,
+    Release,
+    Research,
+    Writing,
+    Career,
+    Community,
+    Milestone,
+}
+
+impl TimelineCategory {
+    pub const ALL: [Self; 7] = [
+        Self::Project,
+        Self::Release,
+        Self::Research,
+        Self::Writing,
+        Self::Career,
+        Self::Community,
+        Self::Milestone,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str().eq_ignore_ascii_case(value))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::Release => "release",
+            Self::Research => "research",
+            Self::Writing => "writing",
+            Self::Career => "career",
+            Self::Community => "community",
+            Self::Milestone => "milestone",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Project => "Projects",
+            Self::Release => "Releases",
+            Self::Research => "Research",
+            Self::Writing => "Writing",
+            Self::Career => "Career",
+            Self::Community => "Community",
+            Self::Milestone => "Milestones",
+        }
+    }
+
+    /// The Font Awesome icon for entries that don't name their own.
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Project => "fas fa-code-branch",
+            Self::Release => "fas fa-tag",
+            Self::Research => "fas fa-flask",
+            Self::Writing => "fas fa-pen-nib",
+            Self::Career => "fas fa-briefcase",
+            Self::Community => "fas fa-people-group",
+            Self::Milestone => "fas fa-flag",
+        }
+    }
+
+    /// The category of a legacy entry, from the words it opens with or
+    /// mentions.
+    fn guess(text: &str) -> Self {
+        let text = text.to_lowercase();
+        let mentions = |words: &[&str]| words.iter().any(|word| text.contains(word));
+        if mentions(&["research article"]) {
+            Self::Research
+        } else if text.starts_with("published") && mentions(&["article"]) {
+            Self::Writing
+        } else if mentions(&["joined", "founded", "transitioned"]) {
+            Self::Career
+        } else if mentions(&[
+            "attended",
+            "spoke at",
+            "hangout",
+            "community",
+            "networked",
+            "mentorship",
+        ]) {
+            Self::Community
+        } else if mentions(&["learn", "exploring"]) {
+            Self::Milestone
+        } else if mentions(&[
+            "created",
+            "started",
+            "built",
+            "shipped",
+            "published",
+            "began work",
+            "worked on",
+            "pull request",
+            "wrote a",
+        ]) {
+            Self::Project
+        } else {
+            Self::Milestone
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineLink {
+    pub label: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineImage {
+    pub src: String,
+    #[serde(default)]
+    pub alt: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    pub date: TimelineDate,
+    /// A line of markdown, so it can link to what it describes.
+    pub title: String,
+    /// Markdown shown under the title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub category: TimelineCategory,
+    /// A Font Awesome class list, in place of the category's icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<TimelineLink>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<TimelineImage>,
+    /// Always [`TimelineSource::Manual`] for entries read from a file.
+    #[serde(skip)]
+    pub source: TimelineSource,
+}
+
+impl TimelineEntry {
+    pub fn icon(&self) -> &str {
+        self.icon.as_deref().unwrap_or_else(|| self.category.icon())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TimelineFile {
+    Entries(Vec<TimelineEntry>),
+    /// `{"2023": {"october": ["Spoke at DevFest", ...]}}`, where a month may
+    /// be a range such as `february - december`.
+    Legacy(BTreeMap<String, BTreeMap<String, Vec<String>>>),
+}
+
+/// Every entry in a timeline file of either layout, newest first. Entries
+/// on the same date keep the file's order, except in legacy files, which
+/// listed each month oldest first.
+pub fn parse_timeline(json: &str) -> Result<Vec<TimelineEntry>, serde_json::Error> {
+    let mut entries = match serde_json::from_str(json)? {
+        TimelineFile::Entries(entries) => entries,
+        TimelineFile::Legacy(years) => years
+            .into_iter()
+            .flat_map(|(year, months)| {
+                months.into_iter().flat_map(move |(month, lines)| {
+                    let date = legacy_date(&year, &month);
+                    lines.into_iter().rev().filter_map(move |title| {
+                        Some(TimelineEntry {
+                            date: date?,
+                            category: TimelineCategory::guess(&title),
+                            title,
+                            body: None,
+                            icon: None,
+                            location: None,
+                            links: Vec::new(),
+                            images: Vec::new(),
+                            source: TimelineSource::Manual,
+                        })
+                    })
+                })
+            })
+            .collect(),
+    };
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.date));
+    Ok(entries)
+}
+
+/// The month a legacy key names, or the first month of a range.
+fn legacy_date(year: &str, month: &str) -> Option<TimelineDate> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let first = month.split('-').next()?.trim().to_lowercase();
+    let month = MONTHS.iter().position(|name| *name == first)? as u32 + 1;
+    Some(TimelineDate {
+        year: year.trim().parse().ok()?,
+        month: Some(month),
+        day: None,
+    })
+}